@@ -0,0 +1,217 @@
+//! The registry of known answers, used to check that the solvers still give the right answers.
+//!
+//! Each year has its own registry, in the data directory of the year. The registry is a text
+//! file with one answer per line: the day, the part, the input file (relative to the registry's
+//! directory) and the answer, separated by whitespace. Blank lines and lines starting with `#`
+//! are ignored.
+//!
+//! ```text
+//! # day part input answer
+//! 2 1 dec2.txt 2476
+//! ```
+
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    input::{year_dir, Source},
+    runner::Part,
+};
+
+/// The name of the registry file in the data directory of a year.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    dir: PathBuf,
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    /// Parse a registry whose input files are relative to `dir`.
+    pub fn parse(content: &str, dir: &Path) -> Result<Self, String> {
+        let answers = content
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+            .map(|(ix, l)| {
+                answer(l).ok_or_else(|| format!("line {}: invalid answer `{l}`", ix + 1))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            answers,
+        })
+    }
+
+    /// Load the registry at `path`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        Self::parse(&content, dir).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// The registry of the data directory of `year`.
+    pub fn default_path(year: u16) -> PathBuf {
+        year_dir(year).join(ANSWERS_FILE)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Answer> {
+        self.answers.iter()
+    }
+
+    /// The path of an input file named in the registry.
+    pub fn input_path(&self, input: &str) -> PathBuf {
+        self.dir.join(input)
+    }
+
+    /// The name in the registry of an input, if it is a file of the registry's directory.
+    pub fn input_name(&self, source: &Source) -> Option<String> {
+        let Source::Path(path) = source else {
+            return None;
+        };
+        let name = path.strip_prefix(&self.dir).ok()?;
+        name.to_str().map(String::from)
+    }
+
+    /// The distinct input files registered for `day`, in registry order.
+    pub fn inputs(&self, day: u8) -> Vec<&str> {
+        let mut inputs: Vec<&str> = vec![];
+        for a in self.answers.iter().filter(|a| a.day == day) {
+            if !inputs.contains(&a.input.as_str()) {
+                inputs.push(&a.input);
+            }
+        }
+        inputs
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.input == input)
+            .map(|a| a.answer.as_str())
+    }
+
+    /// Compare an answer to the registered one.
+    pub fn check(&self, day: u8, part: Part, input: &str, answer: &str) -> Status {
+        match self.get(day, part, input) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail(expected.to_string()),
+            None => Status::Unknown,
+        }
+    }
+}
+
+fn answer(line: &str) -> Option<Answer> {
+    let mut fields = line.split_whitespace();
+    let day = fields.next()?.parse().ok()?;
+    let part = match fields.next()? {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return None,
+    };
+    let input = fields.next()?.to_string();
+    let answer = fields.next()?.to_string();
+    if fields.next().is_some() {
+        return None;
+    }
+    Some(Answer {
+        day,
+        part,
+        input,
+        answer,
+    })
+}
+
+/// The result of checking an answer against the registry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Pass,
+    /// The answer differs from the registered one, given here.
+    Fail(String),
+    /// No answer is registered.
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail(_) => write!(f, "fail"),
+            Status::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        answers::{Answers, Status},
+        input::Source,
+        runner::Part,
+    };
+
+    const REGISTRY: &str = "# day part input answer\n\n2 1 dec2.txt 2476\n2 2 dec2.txt 54911\n";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(REGISTRY, Path::new("data")).unwrap();
+        assert_eq!(answers.iter().count(), 2);
+        assert_eq!(answers.get(2, Part::One, "dec2.txt"), Some("2476"));
+        assert_eq!(answers.get(2, Part::One, "other.txt"), None);
+        assert_eq!(answers.inputs(2), vec!["dec2.txt"]);
+        assert_eq!(answers.input_path("dec2.txt"), Path::new("data/dec2.txt"));
+        assert_eq!(
+            answers.input_name(&Source::from("data/dec2.txt")),
+            Some("dec2.txt".to_string())
+        );
+        assert_eq!(answers.input_name(&Source::from("dec2.txt")), None);
+        assert_eq!(answers.input_name(&Source::Stdin), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Answers::parse("2 1 dec2.txt 2476\n2 3 dec2.txt 1\n", Path::new("")),
+            Err("line 2: invalid answer `2 3 dec2.txt 1`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(REGISTRY, Path::new("data")).unwrap();
+        assert_eq!(
+            answers.check(2, Part::One, "dec2.txt", "2476"),
+            Status::Pass
+        );
+        assert_eq!(
+            answers.check(2, Part::Two, "dec2.txt", "1"),
+            Status::Fail("54911".to_string())
+        );
+        assert_eq!(
+            answers.check(3, Part::One, "dec3.txt", "1"),
+            Status::Unknown
+        );
+    }
+
+    #[test]
+    fn test_default_registry() {
+        let answers = Answers::load(&Answers::default_path(2023)).unwrap();
+        for day in 1..=6 {
+            assert_eq!(answers.inputs(day), vec![format!("dec{day}.txt")]);
+        }
+    }
+}
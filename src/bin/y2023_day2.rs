@@ -0,0 +1,3 @@
+fn main() {
+    aoc2023::runner::main_for_day(2023, 2);
+}
@@ -0,0 +1,376 @@
+//! Client of the Advent of Code website: download of the puzzle inputs and submission of the
+//! answers.
+//!
+//! The requests are authenticated with the session cookie of the website, read from the
+//! `AOC_SESSION` environment variable or from the [configuration file](Config::path):
+//!
+//! ```text
+//! # ~/.config/aoc/config
+//! session = 53616c7465645f5f...
+//! base_url = https://adventofcode.com
+//! ```
+//!
+//! The base URL can be changed, e.g. to a local server in tests.
+
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::runner::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, as asked by the website.
+const USER_AGENT: &str = concat!("aoc2023/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+        }
+    }
+}
+
+impl Config {
+    /// The configuration file: the `AOC_CONFIG` environment variable, else
+    /// `~/.config/aoc/config`.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os("AOC_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+        std::env::var_os("HOME").map(|home| Path::new(&home).join(".config/aoc/config"))
+    }
+
+    /// Parse a configuration file of `key = value` lines, with `#` comments.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut config = Config::default();
+        for (ix, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once('=').map(|(k, v)| (k.trim(), v.trim())) {
+                Some(("session", v)) => config.session = Some(v.to_string()),
+                Some(("base_url", v)) => config.base_url = v.to_string(),
+                Some((k, _)) => return Err(format!("line {}: unknown key `{k}`", ix + 1)),
+                None => return Err(format!("line {}: expected `key = value`", ix + 1)),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Read the configuration file if there is one, then override it with the `AOC_SESSION`
+    /// and `AOC_BASE_URL` environment variables.
+    pub fn load() -> Result<Self, String> {
+        let mut config = match Self::path().filter(|p| p.exists()) {
+            Some(path) => {
+                let content =
+                    fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+                Self::parse(&content).map_err(|e| format!("{}: {e}", path.display()))?
+            }
+            None => Config::default(),
+        };
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+}
+
+/// The response of the website to a submitted answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, with the remaining wait if given, e.g. `34s`.
+    RateLimited(Option<String>),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A response which is not understood, with its text.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Recognize the verdict in the HTML page returned for a submission.
+    pub fn parse(page: &str) -> Self {
+        let text = main_text(page);
+        if text.contains("That's the right answer") {
+            Verdict::Right
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Verdict::RateLimited(wait)
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right answer"),
+            Verdict::Wrong => write!(f, "wrong answer"),
+            Verdict::TooHigh => write!(f, "wrong answer, too high"),
+            Verdict::TooLow => write!(f, "wrong answer, too low"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "answered too recently, wait {wait}"),
+            Verdict::RateLimited(None) => write!(f, "answered too recently"),
+            Verdict::WrongLevel => write!(f, "part already solved or locked"),
+            Verdict::Unknown(text) => write!(f, "unknown response: {text}"),
+        }
+    }
+}
+
+/// The text of the `<main>` element of a page, without the tags and with collapsed spaces.
+fn main_text(page: &str) -> String {
+    let main = page
+        .split_once("<main>")
+        .and_then(|(_, rest)| rest.split_once("</main>"))
+        .map_or(page, |(main, _)| main);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in main.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        let session = config.session.clone().ok_or_else(|| {
+            "no session token: set AOC_SESSION or `session` in the config file".to_string()
+        })?;
+        Ok(Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session,
+        })
+    }
+
+    fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{year}/day/{day}{page}", self.base_url)
+    }
+
+    /// Download the input of a day of `year`.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = self.url(year, day, "/input");
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| http_error(&url, e))?;
+        response.into_string().map_err(|e| format!("{url}: {e}"))
+    }
+
+    /// Submit the answer to a part of a day of `year`.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, String> {
+        let url = self.url(year, day, "/answer");
+        let level = part.number().to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|e| http_error(&url, e))?;
+        let page = response.into_string().map_err(|e| format!("{url}: {e}"))?;
+        Ok(Verdict::parse(&page))
+    }
+}
+
+fn http_error(url: &str, e: ureq::Error) -> String {
+    match e {
+        ureq::Error::Status(400, _) => format!("{url}: rejected, is the session token valid?"),
+        ureq::Error::Status(404, _) => format!("{url}: not found, is the puzzle unlocked?"),
+        ureq::Error::Status(code, _) => format!("{url}: HTTP status {code}"),
+        // The transport errors already name the URL.
+        e => e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use crate::{
+        client::{Client, Config, Verdict},
+        runner::Part,
+    };
+
+    /// A request received by the mock server: its request line, cookie and body.
+    #[derive(Debug)]
+    struct Request {
+        line: String,
+        cookie: String,
+        body: String,
+    }
+
+    /// Serve each of the canned `(status, body)` responses to a connection on a local port,
+    /// and return the configuration to reach it and the received requests.
+    fn mock_server(responses: Vec<(u16, String)>) -> (Config, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let (mut cookie, mut length) = (String::new(), 0);
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(": ").unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = value.to_string(),
+                        "content-length" => length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+                let mut body_bytes = vec![0; length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                sender
+                    .send(Request {
+                        line: line.trim_end().to_string(),
+                        cookie,
+                        body: String::from_utf8(body_bytes).unwrap(),
+                    })
+                    .unwrap();
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        let config = Config {
+            base_url,
+            session: Some("s3cr3t".to_string()),
+        };
+        (config, receiver)
+    }
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_config() {
+        let config = Config::parse("# token\nsession = abc\n\nbase_url=http://localhost:8080\n");
+        assert_eq!(
+            config,
+            Ok(Config {
+                base_url: "http://localhost:8080".to_string(),
+                session: Some("abc".to_string())
+            })
+        );
+        assert!(Config::parse("token = abc").is_err());
+        assert!(Client::new(&Config::default()).is_err());
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (config, requests) = mock_server(vec![
+            (200, "1abc2\n".to_string()),
+            (404, "Not found".to_string()),
+        ]);
+        let client = Client::new(&config).unwrap();
+        assert_eq!(client.fetch_input(2023, 1), Ok("1abc2\n".to_string()));
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2023/day/1/input HTTP/1.1");
+        assert_eq!(request.cookie, "session=s3cr3t");
+
+        let e = client.fetch_input(2022, 25).unwrap_err();
+        assert!(e.ends_with("/2022/day/25/input: not found, is the puzzle unlocked?"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let pages = [
+            "That's the right answer! You are one gold star closer.",
+            "That's not the right answer; your answer is too high.",
+            "That's not the right answer; your answer is too low.",
+            "That's not the right answer. If you're stuck, ...",
+            "You gave an answer too recently. You have 34s left to wait.",
+            "You don't seem to be solving the right level. Did you already complete it?",
+        ];
+        let (config, requests) = mock_server(pages.iter().map(|m| (200, page(m))).collect());
+        let client = Client::new(&config).unwrap();
+        let verdicts: Vec<Verdict> = (0..pages.len())
+            .map(|_| client.submit(2023, 5, Part::Two, "46").unwrap())
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                Verdict::Right,
+                Verdict::TooHigh,
+                Verdict::TooLow,
+                Verdict::Wrong,
+                Verdict::RateLimited(Some("34s".to_string())),
+                Verdict::WrongLevel,
+            ]
+        );
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2023/day/5/answer HTTP/1.1");
+        assert_eq!(request.body, "level=2&answer=46");
+    }
+
+    #[test]
+    fn test_unknown_verdict() {
+        assert_eq!(
+            Verdict::parse("<main>\n<p>Something <em>new</em></p>\n</main>"),
+            Verdict::Unknown("Something new".to_string())
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! The error type of the crate.
+
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The input could not be read.
+    Input(String),
+    /// The input does not have the expected format.
+    Parse(ParseError),
+    /// Several lines of the input do not have the expected format.
+    BadLines(Vec<ParseError>),
+    /// The input is well-formed but breaks an assumption of the puzzle.
+    Validation(String),
+    /// The puzzle has no answer for this input.
+    NoSolution(String),
+    /// The solver panicked, with this message.
+    Panic(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Name the file the input comes from in a parse error.
+    pub fn in_file(self, file: impl Display) -> Self {
+        let file = file.to_string();
+        let in_file = |e| ParseError {
+            file: Some(file.clone()),
+            ..e
+        };
+        match self {
+            Error::Parse(e) => Error::Parse(in_file(e)),
+            Error::BadLines(errors) => Error::BadLines(errors.into_iter().map(in_file).collect()),
+            e => e,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Input(e) => write!(f, "cannot read input {e}"),
+            Error::Parse(e) => write!(f, "parse error at {e}"),
+            Error::BadLines(errors) => {
+                write!(f, "{} bad lines", errors.len())?;
+                for e in errors {
+                    write!(f, "\nparse error at {e}")?;
+                }
+                Ok(())
+            }
+            Error::Validation(e) => write!(f, "invalid input: {e}"),
+            Error::NoSolution(e) => write!(f, "no solution: {e}"),
+            Error::Panic(e) => write!(f, "panicked: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Where parsing failed in an input and what was expected there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// The line number, from 1.
+    pub line: usize,
+    /// The column number in characters, from 1.
+    pub column: usize,
+    /// The descriptions of the accepted tokens, e.g. "`red`" or "a number".
+    pub expected: Vec<String>,
+    /// The text of the line.
+    pub text: String,
+}
+
+impl ParseError {
+    /// Locate in `source` the failure at `at`, a slice of `source`.
+    pub fn locate(source: &str, at: &str, expected: Vec<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .saturating_sub(source.as_ptr() as usize)
+            .min(source.len());
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |p| p + 1);
+        Self {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected,
+            text: source[line_start..]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+
+    /// What was expected, e.g. "`red`, `green` or `blue`".
+    pub fn expectation(&self) -> String {
+        match self.expected.split_last() {
+            None => "something else".to_string(),
+            Some((last, [])) => last.clone(),
+            Some((last, others)) => format!("{} or {last}", others.join(", ")),
+        }
+    }
+}
+
+impl Display for ParseError {
+    /// `file:line:column: expected ...` followed by the line with a caret under the column.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}",
+            self.line,
+            self.column,
+            self.expectation()
+        )?;
+        let margin = " ".repeat(self.line.to_string().len());
+        writeln!(f)?;
+        writeln!(f, "{margin} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{margin} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Error, ParseError};
+
+    #[test]
+    fn test_locate() {
+        let source = "seeds: 1 2\n\nmap:\n50 98 x\n";
+        let at = &source[source.find('x').unwrap()..];
+        let e = ParseError::locate(source, at, vec!["a number".to_string()]);
+        assert_eq!((e.line, e.column), (4, 7));
+        assert_eq!(e.text, "50 98 x");
+        assert_eq!(e.expectation(), "a number");
+
+        let e = ParseError::locate(source, &source[source.len()..], vec![]);
+        assert_eq!((e.line, e.column), (5, 1));
+        assert_eq!(e.text, "");
+        assert_eq!(e.expectation(), "something else");
+
+        let colors = ["`red`", "`green`", "`blue`"].map(String::from).to_vec();
+        let e = ParseError::locate(source, at, colors);
+        assert_eq!(e.expectation(), "`red`, `green` or `blue`");
+    }
+
+    #[test]
+    fn test_display() {
+        let source = "Game 1: 3 blue, 4 rod";
+        let e = ParseError::locate(
+            source,
+            &source[18..],
+            vec!["`red`".into(), "`green`".into(), "`blue`".into()],
+        );
+        assert_eq!(
+            Error::Parse(e).in_file("data/dec2.txt").to_string(),
+            "parse error at data/dec2.txt:1:19: expected `red`, `green` or `blue`
+  |
+1 | Game 1: 3 blue, 4 rod
+  |                   ^"
+        );
+    }
+}
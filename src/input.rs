@@ -0,0 +1,190 @@
+//! Loading of the puzzle inputs at runtime.
+//!
+//! The inputs are [normalized](normalize) before being parsed, so that the parsers only deal
+//! with one line ending and no trailing whitespace.
+
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// The directory holding the puzzle inputs, in a subdirectory per year, relative to the working
+/// directory or to the crate root.
+pub const DATA_DIR: &str = "data";
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+/// The data directory: `data` in the working directory if there is one, else in the crate root.
+pub fn data_dir() -> PathBuf {
+    let dir = Path::new(DATA_DIR);
+    if dir.is_dir() {
+        dir.to_path_buf()
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(DATA_DIR)
+    }
+}
+
+/// The directory of the puzzle inputs of `year`, e.g. `data/2023`.
+pub fn year_dir(year: u16) -> PathBuf {
+    data_dir().join(year.to_string())
+}
+
+impl Source {
+    /// The default input of a day of `year`: `decN.txt` in the [directory of the year](year_dir).
+    pub fn default_for(year: u16, day: u8) -> Self {
+        Source::Path(year_dir(year).join(format!("dec{day}.txt")))
+    }
+
+    /// Read the whole input.
+    pub fn load(&self) -> io::Result<String> {
+        match self {
+            Source::Path(path) => fs::read_to_string(path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Open the input to read it line by line.
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Path(path) => fs::File::open(path)
+                .map(|f| Box::new(BufReader::new(f)) as Box<dyn BufRead>)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+/// The UTF-8 byte order mark, which some editors put at the start of a file.
+const BOM: char = '\u{feff}';
+
+/// Normalize a puzzle input: drop the byte order mark, turn CRLF line endings into LF, trim the
+/// whitespace at the end of each line, drop the trailing blank lines and end the last line with
+/// a line ending.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let mut normalized = String::with_capacity(input.len() + 1);
+    for line in input.trim_end().lines() {
+        normalized.push_str(normalize_line(line));
+        normalized.push('\n');
+    }
+    if normalized == input {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+/// Normalize a line without its line ending, the first one of an input possibly starting with
+/// a byte order mark.
+pub fn normalize_line(line: &str) -> &str {
+    line.strip_prefix(BOM).unwrap_or(line).trim_end()
+}
+
+impl From<&str> for Source {
+    /// `-` stands for the standard input, anything else is a path.
+    fn from(value: &str) -> Self {
+        if value == "-" {
+            Source::Stdin
+        } else {
+            Source::Path(PathBuf::from(value))
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{
+        input::{normalize, Source},
+        runner::{days, Part, DEFAULT_YEAR},
+    };
+
+    #[test]
+    fn test_source_from() {
+        assert_eq!(Source::from("-"), Source::Stdin);
+        assert_eq!(
+            Source::from("inputs/dec5.txt"),
+            Source::Path(PathBuf::from("inputs/dec5.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_for() {
+        let Source::Path(path) = Source::default_for(2023, 5) else {
+            panic!("default input should be a file");
+        };
+        assert!(path.ends_with("data/2023/dec5.txt"));
+        assert!(Source::default_for(2023, 5)
+            .load()
+            .unwrap()
+            .starts_with("seeds:"));
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let err = Source::from("data/missing.txt").load().unwrap_err();
+        assert!(err.to_string().starts_with("data/missing.txt: "));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\nb\n"), "a\nb\n");
+        assert_eq!(normalize("a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(normalize("\u{feff}a\nb\n"), "a\nb\n");
+        assert_eq!(normalize("a  \nb\t\n"), "a\nb\n");
+        assert_eq!(normalize("a\nb"), "a\nb\n");
+        assert_eq!(normalize("a\n\nb\n\n\n"), "a\n\nb\n");
+        assert_eq!(normalize("  a b\n"), "  a b\n");
+        assert_eq!(normalize(""), "");
+    }
+
+    /// A way an input may be saved, and how to make it from the original input.
+    type Variant = (&'static str, fn(&str) -> String);
+
+    /// Every day gives the same answers on its input saved with each of the variations.
+    #[test]
+    fn test_input_variants() {
+        let variants: [Variant; 5] = [
+            ("crlf", |s| s.replace('\n', "\r\n")),
+            ("bom", |s| format!("\u{feff}{s}")),
+            ("trailing spaces", |s| s.replace('\n', "  \n")),
+            ("no final newline", |s| s.trim_end().to_string()),
+            ("trailing blank lines", |s| format!("{s}\n \n")),
+        ];
+        for day in days(DEFAULT_YEAR) {
+            let input = Source::default_for(day.year, day.day).load().unwrap();
+            let expected = day.solve(&input, &Part::ALL).unwrap();
+            for (name, variant) in variants {
+                assert_eq!(
+                    day.solve(&variant(&input), &Part::ALL),
+                    Ok(expected.clone()),
+                    "day {} with {name}",
+                    day.day
+                );
+            }
+        }
+    }
+}
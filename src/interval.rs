@@ -0,0 +1,197 @@
+//! Closed intervals of integers, e.g. ranges of ids.
+
+use std::fmt::Display;
+
+/// An **non-empty** closed interval (src, len) containing interger >= src and < src + len.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl Interval {
+    pub fn min_max(min: u64, max: u64) -> Self {
+        assert!(max >= min);
+        Self { min, max }
+    }
+
+    pub fn start_len(start: u64, len: u64) -> Self {
+        assert!(len > 0);
+        Self {
+            min: start,
+            max: start + len - 1,
+        }
+    }
+
+    /// The interval of `len` integers from `start`, if it is not empty and its end fits in a
+    /// `u64`.
+    pub fn checked_start_len(start: u64, len: u64) -> Option<Self> {
+        let max = start.checked_add(len.checked_sub(1)?)?;
+        Some(Self { min: start, max })
+    }
+
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        self.max + 1 - self.min
+    }
+
+    #[inline]
+    pub fn lt(&self, v: u64) -> bool {
+        self.max < v
+    }
+
+    #[inline]
+    pub fn gt(&self, v: u64) -> bool {
+        v < self.min
+    }
+
+    /// Return `true` if this interval contains v.
+    #[inline]
+    pub fn contains(&self, v: u64) -> bool {
+        self.min <= v && v <= self.max
+    }
+
+    #[inline]
+    pub fn overlap(&self, other: Interval) -> bool {
+        self.max >= other.min && self.min <= other.max
+    }
+
+    #[inline]
+    pub fn touch(&self, other: Interval) -> bool {
+        self.max + 1 == other.min || other.max + 1 == self.min
+    }
+
+    #[inline]
+    pub fn include(&self, other: Interval) -> bool {
+        self.min <= other.min && other.max <= self.max
+    }
+
+    /// Split this interval into a *left* interval of values less than v and
+    /// a *right* interval of values greater than or equal to v.
+    pub fn split_before(&self, v: u64) -> (Option<Interval>, Option<Interval>) {
+        if v <= self.min {
+            (None, Some(*self))
+        } else if v > self.max {
+            (Some(*self), None)
+        } else {
+            (
+                Some(Interval::min_max(self.min, v - 1)),
+                Some(Interval::min_max(v, self.max)),
+            )
+        }
+    }
+
+    /// Split this interval into a *left* interval of values less than or equal to v and
+    /// a *right* interval of values greater than v.
+    pub fn split_after(&self, v: u64) -> (Option<Interval>, Option<Interval>) {
+        if v >= self.max {
+            (Some(*self), None)
+        } else if v < self.min {
+            (None, Some(*self))
+        } else {
+            (
+                Some(Interval::min_max(self.min, v)),
+                Some(Interval::min_max(v + 1, self.max)),
+            )
+        }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}..{}]", self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::Interval;
+
+    #[test]
+    fn test_checked_start_len() {
+        assert_eq!(
+            Interval::checked_start_len(10, 2),
+            Some(Interval::min_max(10, 11))
+        );
+        assert_eq!(
+            Interval::checked_start_len(u64::MAX, 1),
+            Some(Interval::min_max(u64::MAX, u64::MAX))
+        );
+        assert_eq!(Interval::checked_start_len(10, 0), None);
+        assert_eq!(Interval::checked_start_len(u64::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_interval_contains() {
+        let a = Interval::start_len(10, 2);
+        assert!(!a.contains(9));
+        assert!(a.contains(10));
+        assert!(a.contains(11));
+        assert!(!a.contains(12));
+    }
+
+    #[test]
+    fn test_interval_overlap() {
+        let a = Interval::start_len(1, 3);
+        let b = Interval::start_len(3, 5);
+        let c = Interval::start_len(4, 2);
+        let d = Interval::start_len(6, 3);
+
+        assert!(a.overlap(b));
+        assert!(!a.overlap(c));
+        assert!(b.overlap(c));
+        assert!(!c.overlap(d));
+    }
+
+    #[test]
+    fn test_interval_touch() {
+        let a = Interval::min_max(1, 2); // [1, 2]
+        let b = Interval::min_max(3, 3); // [3]
+        let c = Interval::min_max(4, 5); // [4, 5]
+        assert!(a.touch(b));
+        assert!(b.touch(c));
+    }
+
+    #[test]
+    fn test_interval_split_before() {
+        let a = Interval::min_max(1, 10);
+        assert_eq!(a.split_before(1), (None, Some(a)));
+        assert_eq!(
+            a.split_before(5),
+            (
+                Some(Interval::min_max(1, 4)),
+                Some(Interval::min_max(5, 10))
+            )
+        );
+        assert_eq!(
+            a.split_before(10),
+            (
+                Some(Interval::min_max(1, 9)),
+                Some(Interval::min_max(10, 10))
+            )
+        );
+        assert_eq!(a.split_before(11), (Some(a), None));
+    }
+
+    #[test]
+    fn test_interval_split_after() {
+        let a = Interval::min_max(1, 10);
+        assert_eq!(a.split_after(0), (None, Some(a)));
+        assert_eq!(
+            a.split_after(1),
+            (
+                Some(Interval::min_max(1, 1)),
+                Some(Interval::min_max(2, 10))
+            )
+        );
+        assert_eq!(
+            a.split_after(5),
+            (
+                Some(Interval::min_max(1, 5)),
+                Some(Interval::min_max(6, 10))
+            )
+        );
+        assert_eq!(a.split_after(10), (Some(a), None));
+    }
+}
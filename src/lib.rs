@@ -1,5 +1,20 @@
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
+pub mod answers;
+pub mod client;
+pub mod error;
+pub mod input;
+pub mod interval;
+pub mod logging;
+pub mod matcher;
+pub mod numbers;
+pub mod output;
+pub mod parse;
+pub mod runner;
+pub mod samples;
+pub mod scaffold;
+pub mod solution;
+pub mod store;
+pub mod stream;
+pub mod timing;
+pub mod vocabulary;
+pub mod watch;
+pub mod y2023;
@@ -0,0 +1,66 @@
+//! Logging of the diagnostics of the solvers to the standard error.
+//!
+//! The library logs through the [`log`] macros and is silent until a logger is installed with
+//! [`init`]. The level is `off`, `error`, `warn` (the default), `info`, `debug` or `trace`.
+
+use std::str::FromStr;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// The environment variable giving the level when none is given explicitly.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Parse a level name, e.g. `debug`.
+pub fn parse_level(level: &str) -> Result<LevelFilter, String> {
+    LevelFilter::from_str(level).map_err(|_| {
+        format!("invalid log level `{level}`, expected off, error, warn, info, debug or trace")
+    })
+}
+
+/// Log to the standard error at `level`, or else at the level of [`ENV_VAR`], or else at the
+/// `warn` level.
+pub fn init(level: Option<LevelFilter>) -> Result<(), String> {
+    let level = match level {
+        Some(level) => level,
+        None => match std::env::var(ENV_VAR) {
+            Ok(level) => parse_level(&level).map_err(|e| format!("{ENV_VAR}: {e}"))?,
+            Err(_) => LevelFilter::Warn,
+        },
+    };
+    // A logger may only be set once; later calls only change the level.
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(level);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use log::LevelFilter;
+
+    use crate::logging::parse_level;
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!(parse_level("debug"), Ok(LevelFilter::Debug));
+        assert_eq!(parse_level("OFF"), Ok(LevelFilter::Off));
+        assert!(parse_level("verbose").is_err());
+    }
+}
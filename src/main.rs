@@ -1,3 +1,408 @@
+use aoc2023::{
+    answers::Answers,
+    client::{Client, Config, Verdict},
+    input::Source,
+    logging,
+    output::{self, Format},
+    parse::BadLines,
+    runner::{self, Configs, Day, Options, Part, Report},
+    scaffold,
+    store::Store,
+    vocabulary::Vocabulary,
+    watch,
+    y2023::day1,
+};
+use clap::{Parser, Subcommand};
+use log::LevelFilter;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Advent of Code solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// The level of the diagnostics printed to the standard error: off, error, warn, info,
+    /// debug or trace. Defaults to the `AOC_LOG` environment variable, else warn.
+    #[arg(long, global = true, value_parser = logging::parse_level)]
+    log: Option<LevelFilter>,
+    /// The year of the puzzles.
+    #[arg(long, global = true, default_value_t = runner::DEFAULT_YEAR)]
+    year: u16,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day, or every implemented day with `all`.
+    Run {
+        /// A day number (1-25) or `all`.
+        day: String,
+        /// Only solve this part (1 or 2).
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from this file, or from the standard input with `-`, instead of
+        /// `data/YEAR/decN.txt`. Only valid for a single day.
+        #[arg(long)]
+        input: Option<String>,
+        /// Time the parsing and each part.
+        #[arg(long)]
+        time: bool,
+        /// The number of runs to time.
+        #[arg(long, default_value_t = 10, requires = "time")]
+        iterations: usize,
+        /// `text`, or `json` for one record per day and part.
+        #[arg(long, default_value = "text")]
+        format: Format,
+        /// Skip the lines of the input which cannot be parsed, with a warning, instead of
+        /// failing.
+        #[arg(long)]
+        lenient: bool,
+        /// Read the input line by line, in constant memory, for the days which support it.
+        /// The other days read the whole input.
+        #[arg(long, conflicts_with = "time")]
+        stream: bool,
+        /// Solve the days on that many threads. The timings are only meaningful with 1.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+        /// The words spelling out the digits in part 2 of day 1 of 2023: english (the
+        /// default), french, german, or a vocabulary file with a word and its digit per line.
+        #[arg(long, value_parser = Vocabulary::from_arg)]
+        words: Option<Vocabulary>,
+        /// What makes the calibration values of day 1 of 2023: the first and last `digits` of
+        /// each line, or its first and last `numbers`, spelled out in English in part 2.
+        #[arg(long, default_value = "digits")]
+        mode: day1::Mode,
+        /// What to do with the lines of day 1 of 2023 with no digit: `fail`, `skip` them, or
+        /// count them as `zero`. How many lines were skipped or counted as zero is reported with
+        /// the answers.
+        #[arg(long, default_value = "fail")]
+        no_digit: day1::NoDigit,
+    },
+    /// Generate the module, binary and data files of a new day and register it.
+    NewDay {
+        /// The day number (1-25).
+        day: u8,
+    },
+    /// Check the answers of every day against the answer registry.
+    Verify {
+        /// The answer registry, `data/YEAR/answers.txt` by default.
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Solve a day again each time its input, sample or expected answer files change.
+    Watch {
+        /// The day number (1-25).
+        day: u8,
+        /// How often to check the files, in milliseconds.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Download the input of a day into `data/YEAR/decN.txt`.
+    Fetch {
+        /// The day number (1-25).
+        day: u8,
+        /// Replace the input file if it is not empty.
+        #[arg(long)]
+        force: bool,
+        /// Save the input in the input store as the input of this user instead.
+        #[arg(long)]
+        user: Option<String>,
+    },
+    /// Save a file, or the standard input with `-`, in the input store as the input of a user.
+    Store {
+        /// The day number (1-25).
+        day: u8,
+        /// The user whose input it is.
+        user: String,
+        /// The input file, or `-` for the standard input.
+        input: String,
+    },
+    /// Solve a day, or every implemented day with `all`, on each input of the input store and
+    /// print the answers side by side.
+    Matrix {
+        /// A day number (1-25) or `all`.
+        day: String,
+        /// Solve the inputs on that many threads.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+    },
+    /// Submit the answer to a part of a day.
+    Submit {
+        /// The day number (1-25).
+        day: u8,
+        /// The part (1 or 2).
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// The answer, by default the one computed on the default input.
+        answer: Option<String>,
+    },
+}
+
 fn main() {
-    eprintln!("Use cargo run --bin <day1|day2...>");
+    let cli = Cli::parse();
+    if let Err(e) = logging::init(cli.log) {
+        eprintln!("{e}");
+        std::process::exit(2);
+    }
+    let year = cli.year;
+    let ok = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            time,
+            iterations,
+            format,
+            lenient,
+            stream,
+            jobs,
+            words,
+            mode,
+            no_digit,
+        } => {
+            let mut options = Options {
+                parts: match part {
+                    Some(1) => vec![Part::One],
+                    Some(_) => vec![Part::Two],
+                    None => Part::ALL.to_vec(),
+                },
+                // The JSON records always carry a timing, of a single run by default.
+                iterations: match (time, format) {
+                    (true, _) => Some(iterations),
+                    (false, Format::Json) => Some(1),
+                    (false, Format::Text) => None,
+                },
+                stream,
+                configs: Configs::default(),
+                bad_lines: if lenient {
+                    BadLines::Skip
+                } else {
+                    BadLines::Fail
+                },
+            };
+            options.configs.set(day1::Config {
+                mode,
+                no_digit,
+                ..day1::Config::new(&words.unwrap_or_default())
+            });
+            let days = if day == "all" {
+                if input.is_some() {
+                    eprintln!("--input cannot be used with `all`");
+                    std::process::exit(2);
+                }
+                runner::days(year)
+                    .iter()
+                    .map(|d| (d, Source::default_for(year, d.day)))
+                    .collect()
+            } else {
+                let d = find_day(year, &day);
+                let source = input
+                    .as_deref()
+                    .map(Source::from)
+                    .unwrap_or_else(|| Source::default_for(year, d.day));
+                vec![(d, source)]
+            };
+            let reports = runner::solve_days(&days, &options, usize::from(jobs));
+            match format {
+                Format::Text => {
+                    let mut ok = true;
+                    for report in &reports {
+                        ok &= runner::print_report(report, &options.parts);
+                    }
+                    ok
+                }
+                Format::Json => {
+                    let answers = Answers::load(&Answers::default_path(year)).ok();
+                    print!(
+                        "{}",
+                        output::json(&reports, &options.parts, answers.as_ref())
+                    );
+                    reports.iter().all(Report::is_solved)
+                }
+            }
+        }
+        Command::NewDay { day } => {
+            match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day) {
+                Ok(files) => {
+                    for file in files {
+                        println!("{}", file.display());
+                    }
+                    true
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    false
+                }
+            }
+        }
+        Command::Verify { answers } => {
+            let path = answers.unwrap_or_else(|| Answers::default_path(year));
+            match Answers::load(&path) {
+                Ok(answers) => runner::verify(year, &answers),
+                Err(e) => {
+                    eprintln!("{e}");
+                    false
+                }
+            }
+        }
+        Command::Watch { day, interval } => {
+            let d = find_day(year, &day.to_string());
+            watch::watch(d, Duration::from_millis(interval))
+        }
+        Command::Fetch { day, force, user } => match fetch(year, day, force, user.as_deref()) {
+            Ok(path) => {
+                println!("{}", path.display());
+                true
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                false
+            }
+        },
+        Command::Store { day, user, input } => {
+            let stored = Source::from(input.as_str())
+                .load()
+                .map_err(|e| e.to_string())
+                .and_then(|input| Store::default().add(year, day, &user, &input));
+            match stored {
+                Ok(path) => {
+                    println!("{}", path.display());
+                    true
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    false
+                }
+            }
+        }
+        Command::Matrix { day, jobs } => {
+            let days: Vec<&Day> = if day == "all" {
+                runner::days(year).iter().collect()
+            } else {
+                vec![find_day(year, &day)]
+            };
+            matrix(year, &days, usize::from(jobs))
+        }
+        Command::Submit { day, part, answer } => {
+            let part = if part == 1 { Part::One } else { Part::Two };
+            match submit(year, day, part, answer) {
+                Ok(verdict) => {
+                    println!("Day {day} part {}: {verdict}", part.number());
+                    verdict == Verdict::Right
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    false
+                }
+            }
+        }
+    };
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+/// The registered day of `year` numbered `day`, exiting if there is none.
+fn find_day(year: u16, day: &str) -> &'static Day {
+    match day.parse().ok().and_then(|day| runner::find(year, day)) {
+        Some(d) => d,
+        None => {
+            eprintln!("Day {day} of {year} is not implemented");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Download the input of a day of `year` to its default path, or to the input store, and
+/// return that path.
+fn fetch(year: u16, day: u8, force: bool, user: Option<&str>) -> Result<PathBuf, String> {
+    let path = match user {
+        Some(user) => Store::default().path(year, day, user)?,
+        None => match Source::default_for(year, day) {
+            Source::Path(path) => path,
+            Source::Stdin => unreachable!("the default input is a file"),
+        },
+    };
+    if !force && fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+        return Err(format!(
+            "{} already exists, use --force to replace it",
+            path.display()
+        ));
+    }
+    let input = Client::new(&Config::load()?)?.fetch_input(year, day)?;
+    match user {
+        Some(user) => Store::default().add(year, day, user, &input),
+        None => {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+            }
+            fs::write(&path, input).map_err(|e| format!("{}: {e}", path.display()))?;
+            Ok(path)
+        }
+    }
+}
+
+/// Print the answer matrix of each day of `year` which has stored inputs.
+///
+/// Return `false` if an input could not be solved.
+fn matrix(year: u16, days: &[&Day], jobs: usize) -> bool {
+    let store = Store::default();
+    let mut inputs = vec![];
+    for &d in days {
+        match store.inputs(year, d.day) {
+            Ok(stored) => inputs.extend(stored.into_iter().map(|(user, path)| (d, user, path))),
+            Err(e) => {
+                eprintln!("{e}");
+                return false;
+            }
+        }
+    }
+    if inputs.is_empty() {
+        eprintln!("No stored input in {}", store.root.display());
+        return false;
+    }
+    let sources: Vec<(&Day, Source)> = inputs
+        .iter()
+        .map(|(d, _, path)| (*d, Source::Path(path.clone())))
+        .collect();
+    let options = Options::default();
+    let mut reports = runner::solve_days(&sources, &options, jobs).into_iter();
+    let mut ok = true;
+    for &d in days {
+        let rows: Vec<(String, Report)> = inputs
+            .iter()
+            .filter(|(day, _, _)| day.day == d.day)
+            .map(|(_, user, _)| (user.clone(), reports.next().unwrap()))
+            .collect();
+        if rows.is_empty() {
+            continue;
+        }
+        ok &= rows.iter().all(|(_, r)| r.is_solved());
+        print!("{}", output::matrix(d.day, &rows, &options.parts));
+        println!();
+    }
+    ok
+}
+
+/// Submit `answer`, or the answer computed on the default input of a day of `year`.
+fn submit(year: u16, day: u8, part: Part, answer: Option<String>) -> Result<Verdict, String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let d = runner::find(year, day)
+                .ok_or_else(|| format!("Day {day} of {year} is not implemented"))?;
+            let options = Options {
+                parts: vec![part],
+                ..Options::default()
+            };
+            let report = runner::solve_day(d, &Source::default_for(year, day), &options);
+            let mut answers = report.run.map_err(|e| e.to_string())?.answers;
+            answers.remove(0).map_err(|e| e.to_string())?
+        }
+    };
+    Client::new(&Config::load()?)?.submit(year, day, part, &answer)
 }
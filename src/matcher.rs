@@ -0,0 +1,172 @@
+//! Finding every occurrence of a set of words in a text, in one pass.
+//!
+//! A [`Matcher`] is an Aho-Corasick automaton built once from a table of words and their values.
+//! It reports the overlapping occurrences too, e.g. both "two" and "one" in "twone", which is
+//! what tokenizing a text with a dictionary of words sharing letters needs.
+
+use std::collections::VecDeque;
+
+/// A state of the automaton: the node of the trie of the words reached by the text read so far.
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// The transitions of the trie, by byte.
+    next: Vec<(u8, usize)>,
+    /// The node of the longest proper suffix of this node which is also in the trie.
+    fail: usize,
+    /// The words ending at this node, longest first.
+    words: Vec<usize>,
+}
+
+/// A matcher of the words of a dictionary, each with a value.
+#[derive(Debug, Clone)]
+pub struct Matcher<V> {
+    nodes: Vec<Node>,
+    /// The length and the value of each word.
+    words: Vec<(usize, V)>,
+}
+
+/// An occurrence of a word in a text, at the byte range `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a, V> {
+    pub start: usize,
+    pub end: usize,
+    pub value: &'a V,
+}
+
+impl<V> Matcher<V> {
+    /// Build the matcher of the given words. A word listed twice keeps its first value.
+    ///
+    /// Panics if a word is empty.
+    pub fn new<W: AsRef<str>>(words: impl IntoIterator<Item = (W, V)>) -> Self {
+        let mut matcher = Self {
+            nodes: vec![Node::default()],
+            words: vec![],
+        };
+        for (word, value) in words {
+            let word = word.as_ref();
+            assert!(!word.is_empty(), "cannot match an empty word");
+            let mut node = 0;
+            for b in word.bytes() {
+                node = match matcher.child(node, b) {
+                    Some(child) => child,
+                    None => {
+                        matcher.nodes.push(Node::default());
+                        let child = matcher.nodes.len() - 1;
+                        matcher.nodes[node].next.push((b, child));
+                        child
+                    }
+                };
+            }
+            if matcher.nodes[node].words.is_empty() {
+                matcher.nodes[node].words.push(matcher.words.len());
+                matcher.words.push((word.len(), value));
+            }
+        }
+        matcher.link();
+        matcher
+    }
+
+    fn child(&self, node: usize, b: u8) -> Option<usize> {
+        self.nodes[node]
+            .next
+            .iter()
+            .find(|(c, _)| *c == b)
+            .map(|(_, child)| *child)
+    }
+
+    /// Compute the failure links breadth first, so that the link of a node is computed after
+    /// the links of the shorter nodes, and add to each node the words ending at its link.
+    fn link(&mut self) {
+        let mut queue = VecDeque::from([0]);
+        while let Some(node) = queue.pop_front() {
+            for (b, child) in self.nodes[node].next.clone() {
+                let fail = if node == 0 {
+                    0
+                } else {
+                    self.step(self.nodes[node].fail, b)
+                };
+                let inherited = self.nodes[fail].words.clone();
+                let child_node = &mut self.nodes[child];
+                child_node.fail = fail;
+                child_node.words.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    /// The state after reading `b` in `node`.
+    fn step(&self, mut node: usize, b: u8) -> usize {
+        loop {
+            if let Some(child) = self.child(node, b) {
+                return child;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    /// Every occurrence of the words in `text`, overlapping or not, in the order of their end,
+    /// and the longest first for the occurrences ending at the same byte.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match<'a, V>> + 'a {
+        let mut node = 0;
+        text.bytes().enumerate().flat_map(move |(ix, b)| {
+            node = self.step(node, b);
+            self.nodes[node].words.iter().map(move |&w| {
+                let (len, value) = &self.words[w];
+                Match {
+                    start: ix + 1 - len,
+                    end: ix + 1,
+                    value,
+                }
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matcher::{Match, Matcher};
+
+    fn values(matcher: &Matcher<u32>, text: &str) -> Vec<(usize, u32)> {
+        matcher
+            .find_iter(text)
+            .map(|m| (m.start, *m.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let matcher = Matcher::new([("one", 1), ("two", 2), ("three", 3), ("eight", 8)]);
+        assert_eq!(
+            values(&matcher, "twoneighthree"),
+            vec![(0, 2), (2, 1), (4, 8), (8, 3)]
+        );
+        assert_eq!(values(&matcher, "tw one"), vec![(3, 1)]);
+        assert_eq!(values(&matcher, ""), vec![]);
+    }
+
+    #[test]
+    fn test_nested() {
+        let matcher = Matcher::new([("he", 1), ("she", 2), ("hers", 3), ("his", 4), ("e", 5)]);
+        assert_eq!(
+            values(&matcher, "ushers"),
+            vec![(1, 2), (2, 1), (3, 5), (2, 3)]
+        );
+        assert_eq!(
+            matcher.find_iter("his").next(),
+            Some(Match {
+                start: 0,
+                end: 3,
+                value: &4
+            })
+        );
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let matcher = Matcher::new([("a", 1), ("a", 2)]);
+        assert_eq!(values(&matcher, "aa"), vec![(0, 1), (1, 1)]);
+    }
+}
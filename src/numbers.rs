@@ -0,0 +1,179 @@
+//! Numbers written with digits or spelled out in English in free text, e.g. "42",
+//! "twenty-three" or "one hundred and five".
+//!
+//! The spelled out numbers need not be separated from the text around them, nor their words
+//! from one another, so that "xtwentyone" holds 21, but they do not overlap: "twone" only holds
+//! 2. A spelled out number is read as far as possible, so "twenty one" is 21, not 20 and 1.
+
+use nom::{
+    branch::alt,
+    character::complete::char,
+    combinator::opt,
+    sequence::{pair, preceded, terminated},
+    Parser,
+};
+
+use crate::parse::{tag, Expected, PResult};
+
+const UNITS: [(&str, u64); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const TEENS: [(&str, u64); 10] = [
+    ("ten", 10),
+    ("eleven", 11),
+    ("twelve", 12),
+    ("thirteen", 13),
+    ("fourteen", 14),
+    ("fifteen", 15),
+    ("sixteen", 16),
+    ("seventeen", 17),
+    ("eighteen", 18),
+    ("nineteen", 19),
+];
+
+const TENS: [(&str, u64); 8] = [
+    ("twenty", 20),
+    ("thirty", 30),
+    ("forty", 40),
+    ("fifty", 50),
+    ("sixty", 60),
+    ("seventy", 70),
+    ("eighty", 80),
+    ("ninety", 90),
+];
+
+/// One of `words`, none of which may start another one.
+fn one_of<'a>(words: &'static [(&'static str, u64)]) -> impl FnMut(&'a str) -> PResult<'a, u64> {
+    move |input: &'a str| {
+        words
+            .iter()
+            .find_map(|(word, value)| Some((input.strip_prefix(word)?, *value)))
+            .ok_or_else(|| nom::Err::Error(Expected::new(input, "a number word")))
+    }
+}
+
+/// What may separate the words of a number: a space, a hyphen or nothing.
+fn sep(input: &str) -> PResult<'_, ()> {
+    opt(alt((char(' '), char('-')))).map(|_| ()).parse(input)
+}
+
+/// What may follow "hundred" or "thousand" in a number, e.g. " and ".
+fn and(input: &str) -> PResult<'_, ()> {
+    pair(sep, opt(pair(tag("and"), sep)))
+        .map(|_| ())
+        .parse(input)
+}
+
+/// 1 to 99, e.g. "seven", "seventeen" or "seventy-seven".
+fn below_hundred(input: &str) -> PResult<'_, u64> {
+    // The tens and the teens are tried before the units they start with, e.g. "six".
+    alt((
+        pair(one_of(&TENS), opt(preceded(sep, one_of(&UNITS)))).map(|(t, u)| t + u.unwrap_or(0)),
+        one_of(&TEENS),
+        one_of(&UNITS),
+    ))
+    .parse(input)
+}
+
+/// 1 to 999, e.g. "nine hundred and ninety-nine".
+fn below_thousand(input: &str) -> PResult<'_, u64> {
+    alt((
+        pair(
+            terminated(one_of(&UNITS), pair(sep, tag("hundred"))),
+            opt(preceded(and, below_hundred)),
+        )
+        .map(|(h, rest)| 100 * h + rest.unwrap_or(0)),
+        below_hundred,
+    ))
+    .parse(input)
+}
+
+/// A number spelled out in English, from 0 to 999999, e.g. "one thousand two hundred".
+pub fn number(input: &str) -> PResult<'_, u64> {
+    alt((
+        pair(
+            terminated(below_thousand, pair(sep, tag("thousand"))),
+            opt(preceded(and, below_thousand)),
+        )
+        .map(|(t, rest)| 1000 * t + rest.unwrap_or(0)),
+        below_thousand,
+        tag("zero").map(|_| 0),
+    ))
+    .parse(input)
+}
+
+/// The numbers of `text`, written with digits or spelled out, in order.
+pub fn numbers(text: &str) -> Vec<u64> {
+    scan(text, true)
+}
+
+/// The numbers of `text` written with digits, in order.
+pub fn written(text: &str) -> Vec<u64> {
+    scan(text, false)
+}
+
+fn scan(text: &str, spelled: bool) -> Vec<u64> {
+    let mut numbers = vec![];
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if c.is_ascii_digit() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            // A number too large for a u64 is skipped as a whole.
+            if let Ok(n) = rest[..end].parse() {
+                numbers.push(n);
+            }
+            rest = &rest[end..];
+        } else if let Some((after, n)) = spelled.then(|| number(rest).ok()).flatten() {
+            numbers.push(n);
+            rest = after;
+        } else {
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::numbers::{number, numbers, written};
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number("seven"), Ok(("", 7)));
+        assert_eq!(number("seventeen"), Ok(("", 17)));
+        assert_eq!(number("seventy-seven"), Ok(("", 77)));
+        assert_eq!(number("twenty three"), Ok(("", 23)));
+        assert_eq!(number("one hundred and five"), Ok(("", 105)));
+        assert_eq!(number("nine hundred ninety-nine"), Ok(("", 999)));
+        assert_eq!(number("one hundred thousand"), Ok(("", 100000)));
+        assert_eq!(number("two thousand and twenty-three"), Ok(("", 2023)));
+        assert_eq!(number("six hundred and"), Ok((" and", 600)));
+        assert_eq!(number("zero"), Ok(("", 0)));
+        assert!(number("hundred").is_err());
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(
+            numbers("twenty-three, one hundred and five, 7"),
+            vec![23, 105, 7]
+        );
+        assert_eq!(numbers("xtwentyone3fourteen"), vec![21, 3, 14]);
+        assert_eq!(numbers("twoneight"), vec![2, 8]);
+        assert_eq!(numbers("seven eight"), vec![7, 8]);
+        assert_eq!(numbers("no number"), vec![]);
+        assert_eq!(written("a12b3 four"), vec![12, 3]);
+        assert_eq!(written("99999999999999999999 1"), vec![1]);
+    }
+}
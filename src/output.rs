@@ -0,0 +1,304 @@
+//! Formatting of the runner's results, for people or for other tools.
+
+use std::str::FromStr;
+
+use crate::{
+    answers::{Answers, Status},
+    runner::{Part, Report},
+    timing::Stats,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// A JSON array with one record per day and part.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format `{s}`, expected `text` or `json`")),
+        }
+    }
+}
+
+/// The answers of a day, one part per line.
+pub fn text(report: &Report, parts: &[Part]) -> String {
+    let run = match &report.run {
+        Ok(run) => run,
+        Err(e) => return format!("Day {}: {e}\n", report.day),
+    };
+    let mut out = format!("Answer for Day {}:\n", report.day);
+    if let Some((parse, _)) = &run.timings {
+        out += &format!("\tparse: {parse}\n");
+    }
+    for (ix, (part, answer)) in parts.iter().zip(&run.answers).enumerate() {
+        let answer = match (answer, run.remarks.get(ix).and_then(Option::as_ref)) {
+            (Ok(answer), Some(remark)) => format!("{answer}, {remark}"),
+            (Ok(answer), None) => answer.clone(),
+            (Err(e), _) => e.to_string(),
+        };
+        match &run.timings {
+            Some((_, times)) => {
+                out += &format!("\tpart {}: {answer} ({})\n", part.number(), times[ix])
+            }
+            None => out += &format!("\tpart {}: {answer}\n", part.number()),
+        }
+    }
+    out
+}
+
+/// A table of the answers of a day on several inputs, with an input per row and a part per
+/// column. An error is shortened to its first line.
+pub fn matrix(day: u8, rows: &[(String, Report)], parts: &[Part]) -> String {
+    let mut table = vec![std::iter::once(format!("Day {day}"))
+        .chain(parts.iter().map(|p| format!("part {}", p.number())))
+        .collect::<Vec<_>>()];
+    for (name, report) in rows {
+        let cells = match &report.run {
+            Ok(run) => run
+                .answers
+                .iter()
+                .map(|a| match a {
+                    Ok(answer) => answer.clone(),
+                    Err(e) => first_line(&format!("error: {e}")),
+                })
+                .collect(),
+            Err(e) => vec![first_line(&format!("error: {e}"))],
+        };
+        table.push(std::iter::once(name.clone()).chain(cells).collect());
+    }
+
+    let columns = table.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|c| {
+            // The last cell of a row is not padded, so that an error of a whole row does not
+            // widen the first part.
+            table
+                .iter()
+                .filter(|row| c + 1 < row.len())
+                .map(|row| row[c].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut out = String::new();
+    for row in table {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect();
+        out += line.join("  ").trim_end();
+        out.push('\n');
+    }
+    out
+}
+
+fn first_line(s: &str) -> String {
+    s.lines().next().unwrap_or_default().to_string()
+}
+
+/// A JSON array with a record per day and part giving the answer, the timings, the input and the
+/// status of the answer in the registry, if any.
+pub fn json(reports: &[Report], parts: &[Part], answers: Option<&Answers>) -> String {
+    let mut records = vec![];
+    for report in reports {
+        let input = json_string(&report.source.to_string());
+        for (ix, part) in parts.iter().enumerate() {
+            let mut fields = vec![
+                format!("\"year\": {}", report.year),
+                format!("\"day\": {}", report.day),
+                format!("\"part\": {}", part.number()),
+                format!("\"input\": {input}"),
+            ];
+            match report
+                .run
+                .as_ref()
+                .and_then(|run| Ok((run, run.answers[ix].as_ref()?)))
+            {
+                Ok((run, answer)) => {
+                    let status = answers
+                        .and_then(|a| {
+                            let name = a.input_name(&report.source)?;
+                            Some(a.check(report.day, *part, &name, answer))
+                        })
+                        .unwrap_or(Status::Unknown);
+                    fields.push(format!("\"answer\": {}", json_string(answer)));
+                    fields.push(format!("\"status\": \"{status}\""));
+                    if let Status::Fail(expected) = &status {
+                        fields.push(format!("\"expected\": {}", json_string(expected)));
+                    }
+                    if let Some(remark) = run.remarks.get(ix).and_then(Option::as_ref) {
+                        fields.push(format!("\"remark\": {}", json_string(remark)));
+                    }
+                    match &run.timings {
+                        Some((parse, times)) => {
+                            fields.push(format!("\"parse_time\": {}", json_stats(parse)));
+                            fields.push(format!("\"time\": {}", json_stats(&times[ix])));
+                        }
+                        None => {
+                            fields.push("\"parse_time\": null".to_string());
+                            fields.push("\"time\": null".to_string());
+                        }
+                    }
+                }
+                Err(e) => {
+                    fields.push("\"answer\": null".to_string());
+                    fields.push("\"status\": \"error\"".to_string());
+                    fields.push(format!("\"error\": {}", json_string(&e.to_string())));
+                }
+            }
+            records.push(format!("  {{{}}}", fields.join(", ")));
+        }
+    }
+    if records.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", records.join(",\n"))
+    }
+}
+
+fn json_stats(stats: &Stats) -> String {
+    format!(
+        "{{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.max.as_nanos()
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, time::Duration};
+
+    use crate::{
+        answers::Answers,
+        error::Error,
+        input::Source,
+        output::{json, json_string, matrix, text},
+        runner::{Part, Report, Run},
+        timing::Stats,
+    };
+
+    fn report() -> Report {
+        let stats = Stats::new(&[Duration::from_nanos(10)]);
+        Report {
+            year: 2023,
+            day: 2,
+            source: Source::from("data/2023/dec2.txt"),
+            run: Ok(Run {
+                answers: vec![Ok("2476".to_string()), Ok("1".to_string())],
+                remarks: vec![None, None],
+                timings: Some((stats, vec![stats, stats])),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_text() {
+        let mut report = report();
+        report.run.as_mut().unwrap().timings = None;
+        assert_eq!(
+            text(&report, &Part::ALL),
+            "Answer for Day 2:\n\tpart 1: 2476\n\tpart 2: 1\n"
+        );
+        report.run.as_mut().unwrap().remarks[1] = Some("skipped 2 lines".to_string());
+        assert_eq!(
+            text(&report, &Part::ALL),
+            "Answer for Day 2:\n\tpart 1: 2476\n\tpart 2: 1, skipped 2 lines\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let answers = Answers::parse(
+            "2 1 dec2.txt 2476\n2 2 dec2.txt 54911\n",
+            Path::new("data/2023"),
+        )
+        .unwrap();
+        let time = "{\"min_ns\": 10, \"median_ns\": 10, \"max_ns\": 10}";
+        assert_eq!(
+            json(&[report()], &Part::ALL, Some(&answers)),
+            format!(
+                "[\n  {{\"year\": 2023, \"day\": 2, \"part\": 1, \"input\": \"data/2023/dec2.txt\", \"answer\": \"2476\", \"status\": \"pass\", \"parse_time\": {time}, \"time\": {time}}},\n  {{\"year\": 2023, \"day\": 2, \"part\": 2, \"input\": \"data/2023/dec2.txt\", \"answer\": \"1\", \"status\": \"fail\", \"expected\": \"54911\", \"parse_time\": {time}, \"time\": {time}}}\n]\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_json_error() {
+        let missing = Report {
+            year: 2023,
+            day: 7,
+            source: Source::Stdin,
+            run: Err(Error::Input("closed".to_string())),
+        };
+        assert_eq!(
+            json(&[missing], &[Part::One], None),
+            "[\n  {\"year\": 2023, \"day\": 7, \"part\": 1, \"input\": \"<stdin>\", \"answer\": null, \"status\": \"error\", \"error\": \"cannot read input closed\"}\n]\n"
+        );
+
+        let mut report = report();
+        report.run.as_mut().unwrap().remarks[0] = Some("skipped 2 lines".to_string());
+        assert!(json(&[report.clone()], &Part::ALL, None).contains(
+            "\"answer\": \"2476\", \"status\": \"unknown\", \"remark\": \"skipped 2 lines\", \"parse_time\""
+        ));
+        report.run.as_mut().unwrap().answers[1] = Err(Error::NoSolution("no seeds".to_string()));
+        assert!(json(&[report], &Part::ALL, None).contains(
+            "\"part\": 2, \"input\": \"data/2023/dec2.txt\", \"answer\": null, \"status\": \"error\", \"error\": \"no solution: no seeds\"}"
+        ));
+    }
+
+    #[test]
+    fn test_matrix() {
+        let mut bob = report();
+        bob.run.as_mut().unwrap().answers[1] = Err(Error::NoSolution("none\nat all".to_string()));
+        let failed = Report {
+            run: Err(Error::Input("closed".to_string())),
+            ..report()
+        };
+        let rows = vec![
+            ("alice".to_string(), report()),
+            ("bob".to_string(), bob),
+            ("carol".to_string(), failed),
+        ];
+        assert_eq!(
+            matrix(2, &rows, &Part::ALL),
+            "Day 2  part 1  part 2
+alice  2476    1
+bob    2476    error: no solution: none
+carol  error: cannot read input closed
+"
+        );
+    }
+}
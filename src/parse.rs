@@ -0,0 +1,371 @@
+//! Building blocks of the nom parsers of the days.
+//!
+//! The parsers use [`Expected`] as error type so that a failure can be reported with what was
+//! expected where: see [`parse_all`] and [`parse_within`].
+//!
+//! Besides, this module has the parsers common to many puzzles: [`integer`]s, space separated
+//! [`number_list`]s, `Header: values` lines ([`labeled`]), numbers written with spaces between
+//! the digits ([`kerned`]), and [`lines`] and blank line separated [`sections`].
+//!
+//! The inputs made of one record per line are parsed with [`parse_lines`], which reports every
+//! bad line, or skips them as set with [`BadLines`].
+
+use std::str::FromStr;
+
+use log::warn;
+use nom::{
+    character::complete::{char, digit1, line_ending, none_of, space1},
+    combinator::{all_consuming, cut, map_res, opt, peek, recognize},
+    error::{ContextError, ErrorKind, FromExternalError, ParseError},
+    multi::separated_list1,
+    sequence::{pair, preceded, terminated, tuple},
+    Finish, IResult, Parser,
+};
+
+use crate::error::{self, Error, Result};
+
+/// The error of the parsers: the input where parsing failed and a description of what was
+/// expected there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected<'a> {
+    pub input: &'a str,
+    pub expected: Vec<String>,
+}
+
+pub type PResult<'a, O> = IResult<&'a str, O, Expected<'a>>;
+
+impl<'a> Expected<'a> {
+    pub fn new(input: &'a str, expected: impl Into<String>) -> Self {
+        Self {
+            input,
+            expected: vec![expected.into()],
+        }
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a number".to_string(),
+        ErrorKind::MapRes => "a valid number".to_string(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "a space".to_string(),
+        ErrorKind::CrLf => "a line ending".to_string(),
+        ErrorKind::Eof => "end of input".to_string(),
+        kind => format!("{kind:?}"),
+    }
+}
+
+impl<'a> ParseError<&'a str> for Expected<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self::new(input, describe(kind))
+    }
+
+    /// Keep the innermost error, which is the most precise.
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Self::new(input, format!("`{}`", c.escape_debug()))
+    }
+
+    /// Keep the alternative that went the furthest, or all the alternatives which failed at
+    /// the same place.
+    fn or(mut self, other: Self) -> Self {
+        match self.input.len().cmp(&other.input.len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => {
+                for e in other.expected {
+                    if !self.expected.contains(&e) {
+                        self.expected.push(e);
+                    }
+                }
+                self
+            }
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for Expected<'a> {
+    /// Describe the failure with the context, unless it happened deeper in the input.
+    fn add_context(input: &'a str, ctx: &'static str, other: Self) -> Self {
+        if other.input.len() == input.len() {
+            Self::new(input, ctx)
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Expected<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _e: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+/// Recognize the string `t`, failing with ``expected `t` `` otherwise.
+pub fn tag<'a>(t: &'static str) -> impl FnMut(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| {
+        nom::bytes::complete::tag(t)(input)
+            .map_err(|e: nom::Err<Expected>| e.map(|_| Expected::new(input, quote(t))))
+    }
+}
+
+fn quote(t: &str) -> String {
+    format!("`{}`", t.escape_debug())
+}
+
+/// An integer in base 10, with a `-` sign if negative, e.g. `42` or `-7`.
+pub fn integer<'a, T: FromStr>(input: &'a str) -> PResult<'a, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse).parse(input)
+}
+
+/// Integers separated by spaces, e.g. `41 48  83`.
+pub fn number_list<'a, T: FromStr>(input: &'a str) -> PResult<'a, Vec<T>> {
+    separated_list1(space1, integer).parse(input)
+}
+
+/// A number written as groups of digits separated by spaces, e.g. `7  15   30` for 71530.
+pub fn kerned<'a, T: FromStr>(input: &'a str) -> PResult<'a, T> {
+    map_res(separated_list1(space1, digit1), |g: Vec<&str>| {
+        g.concat().parse()
+    })
+    .parse(input)
+}
+
+/// `label`, a colon, spaces and the `values`, e.g. `Time:  7 15` for `labeled("Time", ...)`.
+pub fn labeled<'a, O>(
+    label: &'static str,
+    values: impl Parser<&'a str, O, Expected<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, O> {
+    preceded(tuple((tag(label), char(':'), space1)), values)
+}
+
+/// A line ending followed by a line which is not blank.
+pub fn next_line(input: &str) -> PResult<'_, ()> {
+    terminated(line_ending, peek(none_of("\r\n")))
+        .map(|_| ())
+        .parse(input)
+}
+
+/// One or more lines parsed by `line`, up to a blank line or the end of the input. A non blank
+/// line must be parsed by `line`, so errors are reported in the line.
+pub fn lines<'a, O>(
+    line: impl Parser<&'a str, O, Expected<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>> {
+    separated_list1(next_line, cut(line))
+}
+
+/// One or more sections parsed by `section`, separated by blank lines, e.g. the maps of the
+/// almanac of day 5.
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, O, Expected<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<O>> {
+    separated_list1(pair(line_ending, next_line), cut(section))
+}
+
+/// Run `parser` on the whole of `input`, failing if some input is left.
+pub fn parse_all<'a, O>(
+    parser: impl Parser<&'a str, O, Expected<'a>>,
+    input: &'a str,
+) -> Result<O> {
+    parse_within(input, parser, input)
+}
+
+/// What to do with the lines which cannot be parsed.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum BadLines {
+    /// Fail with the errors of all the bad lines.
+    #[default]
+    Fail,
+    /// Skip them with a warning.
+    Skip,
+}
+
+impl BadLines {
+    /// Fail with the errors of the bad lines if any, or warn that they are skipped.
+    pub fn handle(self, mut errors: Vec<error::ParseError>) -> Result<()> {
+        match self {
+            BadLines::Skip => {
+                for e in &errors {
+                    warn!("skipping line {}: expected {}", e.line, e.expectation());
+                }
+                Ok(())
+            }
+            BadLines::Fail if errors.len() == 1 => Err(Error::Parse(errors.remove(0))),
+            BadLines::Fail if !errors.is_empty() => Err(Error::BadLines(errors)),
+            BadLines::Fail => Ok(()),
+        }
+    }
+}
+
+/// Parse each line of `input` with `parser`. Fail with the errors of every bad line, or skip
+/// them with a warning.
+pub fn parse_lines<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> PResult<'a, O>,
+    bad_lines: BadLines,
+) -> Result<Vec<O>> {
+    let mut records = Vec::new();
+    let mut errors = Vec::new();
+    for line in input.lines() {
+        match parse_within(input, &mut parser, line) {
+            Ok(record) => records.push(record),
+            Err(Error::Parse(e)) => errors.push(e),
+            Err(e) => return Err(e),
+        }
+    }
+    bad_lines.handle(errors)?;
+    Ok(records)
+}
+
+/// Run `parser` on the whole of `part`, a slice of `source`, e.g. a line. A failure is located
+/// in `source`.
+pub fn parse_within<'a, O>(
+    source: &'a str,
+    parser: impl Parser<&'a str, O, Expected<'a>>,
+    part: &'a str,
+) -> Result<O> {
+    all_consuming(parser)
+        .parse(part)
+        .finish()
+        .map(|(_, o)| o)
+        .map_err(|e| Error::Parse(error::ParseError::locate(source, e.input, e.expected)))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        branch::alt,
+        character::complete::{char, digit1},
+        error::context,
+        sequence::pair,
+        Parser,
+    };
+
+    use crate::{
+        error::Error,
+        parse::{
+            integer, kerned, labeled, lines, number_list, parse_all, parse_lines, parse_within,
+            sections, tag, BadLines, Expected,
+        },
+    };
+
+    #[test]
+    fn test_tag() {
+        assert_eq!(tag("red")("red 1"), Ok((" 1", "red")));
+        assert_eq!(
+            tag("red")("blue"),
+            Err(nom::Err::Error(Expected::new("blue", "`red`")))
+        );
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let mut color = alt((tag("red"), tag("green"), tag("blue")));
+        assert_eq!(
+            color("pink"),
+            Err(nom::Err::Error(Expected {
+                input: "pink",
+                expected: vec!["`red`".into(), "`green`".into(), "`blue`".into()]
+            }))
+        );
+        // The alternative which went the furthest wins.
+        let mut p = alt((pair(tag("a"), tag("b")), pair(tag("c"), tag("d"))));
+        assert_eq!(p("ax"), Err(nom::Err::Error(Expected::new("x", "`b`"))));
+    }
+
+    #[test]
+    fn test_context() {
+        let mut p = context("a label", pair(digit1, char(':')));
+        assert_eq!(p("x"), Err(nom::Err::Error(Expected::new("x", "a label"))));
+        assert_eq!(p("1x"), Err(nom::Err::Error(Expected::new("x", "`:`"))));
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(parse_all(digit1, "123"), Ok("123"));
+        let Err(Error::Parse(e)) = parse_all(digit1.and(tag(";")), "123 ") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (1, 4));
+        assert_eq!(e.expected, vec!["`;`".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_within() {
+        let source = "1\n2\nx\n";
+        let line = source.lines().nth(2).unwrap();
+        let Err(Error::Parse(e)) = parse_within(source, digit1, line) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (3, 1));
+        assert_eq!(e.expected, vec!["a number".to_string()]);
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(integer::<u32>("42 x"), Ok((" x", 42)));
+        assert_eq!(integer::<i64>("-7"), Ok(("", -7)));
+        assert_eq!(
+            integer::<u32>("-7"),
+            Err(nom::Err::Error(Expected::new("-7", "a valid number")))
+        );
+        assert_eq!(
+            integer::<u8>("x"),
+            Err(nom::Err::Error(Expected::new("x", "a number")))
+        );
+    }
+
+    #[test]
+    fn test_number_list() {
+        assert_eq!(number_list("41 48  -3\n"), Ok(("\n", vec![41, 48, -3])));
+        assert_eq!(number_list::<u16>("41 |"), Ok((" |", vec![41])));
+    }
+
+    #[test]
+    fn test_kerned() {
+        assert_eq!(kerned("7  15   30\n"), Ok(("\n", 71530_u64)));
+    }
+
+    #[test]
+    fn test_labeled() {
+        let mut time = labeled("Time", number_list::<u64>);
+        assert_eq!(time("Time:   7 15"), Ok(("", vec![7, 15])));
+        assert_eq!(
+            time("Distance: 9"),
+            Err(nom::Err::Error(Expected::new("Distance: 9", "`Time`")))
+        );
+    }
+
+    #[test]
+    fn test_lines_and_sections() {
+        let input = "1\n2\n\n3\n";
+        assert_eq!(
+            sections(lines(integer::<u8>))(input),
+            Ok(("\n", vec![vec![1, 2], vec![3]]))
+        );
+        assert_eq!(
+            lines(integer::<u8>)("1\nx\n"),
+            Err(nom::Err::Failure(Expected::new("x\n", "a number")))
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "1\nx\n3\n4y\n";
+        assert_eq!(
+            parse_lines(input, integer::<u8>, BadLines::Skip),
+            Ok(vec![1, 3])
+        );
+        let Err(Error::BadLines(errors)) = parse_lines(input, integer::<u8>, BadLines::Fail) else {
+            panic!("expected bad lines");
+        };
+        let lines: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(lines, vec![(2, 1), (4, 2)]);
+        assert_eq!(
+            parse_lines("1\n2", integer::<u8>, BadLines::Fail),
+            Ok(vec![1, 2])
+        );
+    }
+}
@@ -0,0 +1,554 @@
+//! Dispatch from a year and a day number to the solver of that day.
+//!
+//! Every implemented day is registered with its [`Solution`] in the `DAYS` table of the module
+//! of its year, e.g. [`y2023::DAYS`], and every year in [`days`].
+//! The `aoc` binary and the per-day binaries all go through these tables.
+
+use crate::{
+    answers::{Answers, Status},
+    error::{Error, Result},
+    input::{self, Source},
+    logging, output,
+    parse::BadLines,
+    solution::Solution,
+    stream::{self, LineSolution},
+    timing::Stats,
+    y2023,
+};
+use log::debug;
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt::Debug,
+    io::BufRead,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Once,
+    },
+    thread,
+    time::Instant,
+};
+
+/// A puzzle part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// The settings of the solvers, at most one of each [`Solution::Config`] type. The solvers
+/// whose settings are not set use the default ones.
+#[derive(Clone, Default)]
+pub struct Configs(Vec<Arc<dyn Any + Send + Sync>>);
+
+impl Configs {
+    /// Set up with `config` the solvers whose settings have its type.
+    pub fn set<C: Any + Send + Sync>(&mut self, config: C) {
+        self.0.retain(|c| !c.is::<C>());
+        self.0.push(Arc::new(config));
+    }
+
+    /// The settings of type `C`, if set.
+    pub fn get<C: Any>(&self) -> Option<&C> {
+        self.0.iter().find_map(|c| c.downcast_ref())
+    }
+}
+
+impl Debug for Configs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Configs({} set)", self.0.len())
+    }
+}
+
+/// How to run the solvers.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub parts: Vec<Part>,
+    /// Time the parsing and each part over that many runs.
+    pub iterations: Option<usize>,
+    /// Read the input line by line for the days which support it, without timing.
+    pub stream: bool,
+    /// The settings of the solvers which have some.
+    pub configs: Configs,
+    /// What to do with the lines of the input which cannot be parsed.
+    pub bad_lines: BadLines,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            parts: Part::ALL.to_vec(),
+            iterations: None,
+            stream: false,
+            configs: Configs::default(),
+            bad_lines: BadLines::Fail,
+        }
+    }
+}
+
+/// The outcome of solving some parts of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    /// The formatted answer of each part, or why it has none.
+    pub answers: Vec<Result<String>>,
+    /// The [remark](Solution::remark) on the answer of each part, if any.
+    pub remarks: Vec<Option<String>>,
+    /// The timings of the parsing, and of each part, when timed.
+    pub timings: Option<(Stats, Vec<Stats>)>,
+}
+
+/// The type-erased [`stream::solve`] of a day.
+type StreamFn = fn(&mut dyn BufRead, &[Part], &Configs, BadLines) -> Result<Run>;
+
+/// A registered day: its year, its number and its type-erased solvers.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    run: fn(&str, &[Part], usize, &Configs, BadLines) -> Result<Run>,
+    stream: Option<StreamFn>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            run: run::<S>,
+            stream: None,
+        }
+    }
+
+    /// Also solve the day line by line with `S`.
+    pub const fn streaming<S: LineSolution>(self) -> Self {
+        Self {
+            stream: Some(stream::solve::<S>),
+            ..self
+        }
+    }
+
+    /// Whether the day can be solved line by line.
+    pub fn can_stream(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Parse the [normalized](input::normalize) input once and return the formatted answer of
+    /// each of the given parts, with the default settings.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<Result<String>>> {
+        let configs = Configs::default();
+        Ok((self.run)(&input::normalize(input), parts, 1, &configs, BadLines::Fail)?.answers)
+    }
+
+    /// Solve the given parts of the [normalized](input::normalize) input, timing the parsing and
+    /// each part if requested.
+    pub fn run(&self, input: &str, options: &Options) -> Result<Run> {
+        let iterations = options.iterations.unwrap_or(1).max(1);
+        let mut run = (self.run)(
+            &input::normalize(input),
+            &options.parts,
+            iterations,
+            &options.configs,
+            options.bad_lines,
+        )?;
+        if options.iterations.is_none() {
+            run.timings = None;
+        }
+        Ok(run)
+    }
+}
+
+/// Parse `input` with the settings of `S` in `configs`, or the default ones, and solve each
+/// part, each step being timed over `iterations` runs.
+fn run<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+    configs: &Configs,
+    bad_lines: BadLines,
+) -> Result<Run> {
+    let default = S::Config::default();
+    let config = configs.get::<S::Config>().unwrap_or(&default);
+    let mut times = Vec::with_capacity(iterations);
+    let mut parsed = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        parsed = Some(S::parse_with(input, config, bad_lines)?);
+        times.push(start.elapsed());
+    }
+    let parsed = parsed.unwrap();
+    let parse = Stats::new(&times);
+    let mut stats = Vec::with_capacity(parts.len());
+
+    let mut answers = Vec::with_capacity(parts.len());
+    let mut remarks = Vec::with_capacity(parts.len());
+    for part in parts {
+        times.clear();
+        let mut answer = None;
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let a = match part {
+                Part::One => S::part_one(&parsed),
+                Part::Two => S::part_two(&parsed),
+            };
+            times.push(start.elapsed());
+            answer = Some(a);
+        }
+        let answer = answer.unwrap();
+        remarks.push(answer.as_ref().ok().and_then(S::remark));
+        answers.push(answer.map(|a| a.to_string()));
+        stats.push(Stats::new(&times));
+    }
+    Ok(Run {
+        answers,
+        remarks,
+        timings: Some((parse, stats)),
+    })
+}
+
+/// The year of the commands which are not given one.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// The registered days of `year`, in order.
+pub fn days(year: u16) -> &'static [Day] {
+    match year {
+        2023 => y2023::DAYS,
+        _ => &[],
+    }
+}
+
+/// Return the registered day of `year` with the given number, if any.
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    days(year).iter().find(|d| d.day == day)
+}
+
+/// The outcome of solving a day on an input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub source: Source,
+    /// The answers, or why they could not be computed.
+    pub run: Result<Run>,
+}
+
+impl Report {
+    /// Whether every part has an answer.
+    pub fn is_solved(&self) -> bool {
+        self.run
+            .as_ref()
+            .is_ok_and(|run| run.answers.iter().all(Result::is_ok))
+    }
+}
+
+thread_local! {
+    /// Whether the panics of this thread are caught by [`solve_day`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Install, once, a panic hook which only logs the panics caught by [`solve_day`], so that they
+/// are reported on one line rather than with a message and a backtrace interleaved with the
+/// output of the other threads. The other panics go to the previous hook.
+fn quiet_caught_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                if let Some(location) = info.location() {
+                    debug!("panicked at {location}");
+                }
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Load the input of a day and solve the given parts. A panic of the solver is reported as an
+/// [`Error::Panic`], its location being logged at the debug level.
+pub fn solve_day(day: &Day, source: &Source, options: &Options) -> Report {
+    quiet_caught_panics();
+    CATCHING.set(true);
+    let run = panic::catch_unwind(AssertUnwindSafe(|| try_solve_day(day, source, options)));
+    CATCHING.set(false);
+    let run = run.unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".to_string());
+        Err(Error::Panic(message))
+    });
+    Report {
+        year: day.year,
+        day: day.day,
+        source: source.clone(),
+        run,
+    }
+}
+
+fn try_solve_day(day: &Day, source: &Source, options: &Options) -> Result<Run> {
+    match day.stream {
+        Some(stream) if options.stream => source
+            .reader()
+            .map_err(|e| Error::Input(e.to_string()))
+            .and_then(|mut reader| {
+                stream(
+                    &mut reader,
+                    &options.parts,
+                    &options.configs,
+                    options.bad_lines,
+                )
+            }),
+        _ => source
+            .load()
+            .map_err(|e| Error::Input(e.to_string()))
+            .and_then(|input| day.run(&input, options)),
+    }
+    .map_err(|e| e.in_file(source))
+}
+
+/// Solve each day on its input with [`solve_day`], on up to `jobs` threads, and return the
+/// reports in the order of `days`.
+pub fn solve_days(days: &[(&Day, Source)], options: &Options, jobs: usize) -> Vec<Report> {
+    let next = AtomicUsize::new(0);
+    let mut reports: Vec<(usize, Report)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, days.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut reports = Vec::new();
+                    loop {
+                        let ix = next.fetch_add(1, Ordering::Relaxed);
+                        let Some((day, source)) = days.get(ix) else {
+                            break;
+                        };
+                        reports.push((ix, solve_day(day, source, options)));
+                    }
+                    reports
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().expect("solve_day catches the panics"))
+            .collect()
+    });
+    reports.sort_by_key(|(ix, _)| *ix);
+    reports.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Load the input of a day, solve the given parts and print the answers.
+///
+/// Return `false` if the day or one of its parts could not be solved.
+pub fn print_day(day: &Day, source: &Source, options: &Options) -> bool {
+    print_report(&solve_day(day, source, options), &options.parts)
+}
+
+/// Print the answers of a report, or its error to the standard error.
+///
+/// Return `false` if the day or one of its parts could not be solved.
+pub fn print_report(report: &Report, parts: &[Part]) -> bool {
+    match &report.run {
+        Ok(_) => print!("{}", output::text(report, parts)),
+        Err(_) => eprint!("{}", output::text(report, parts)),
+    }
+    report.is_solved()
+}
+
+/// Solve every registered day of `year` on each of its inputs in the answer registry of the
+/// year, or on its default input if it has none, and print whether each answer matches the
+/// registered one.
+///
+/// Return `false` if an answer is wrong or an input could not be loaded.
+pub fn verify(year: u16, answers: &Answers) -> bool {
+    let mut ok = true;
+    for day in days(year) {
+        let default_input = format!("dec{}.txt", day.day);
+        let mut inputs = answers.inputs(day.day);
+        if inputs.is_empty() {
+            inputs.push(&default_input);
+        }
+        for name in inputs {
+            let source = Source::Path(answers.input_path(name));
+            let run = match solve_day(day, &source, &Options::default()).run {
+                Ok(run) => run,
+                Err(e) => {
+                    println!("Day {} ({name}): {e}", day.day);
+                    ok = false;
+                    continue;
+                }
+            };
+            for (part, answer) in Part::ALL.iter().zip(run.answers) {
+                let answer = match answer {
+                    Ok(answer) => answer,
+                    Err(e) => {
+                        println!("Day {} part {} ({name}): {e}", day.day, part.number());
+                        ok = false;
+                        continue;
+                    }
+                };
+                let status = answers.check(day.day, *part, name, &answer);
+                print!(
+                    "Day {} part {} ({name}): {status} {answer}",
+                    day.day,
+                    part.number()
+                );
+                if let Status::Fail(expected) = status {
+                    print!(", expected {expected}");
+                    ok = false;
+                }
+                println!();
+            }
+        }
+    }
+    ok
+}
+
+/// Entry point of the per-day binaries: solve and print both parts of a day of `year`.
+///
+/// The input is read from the path given as first argument (`-` for the standard input), or
+/// from the default input of the day. The log level is given by the
+/// [`AOC_LOG`](logging::ENV_VAR) environment variable.
+pub fn main_for_day(year: u16, day: u8) {
+    if let Err(e) = logging::init(None) {
+        eprintln!("{e}");
+        std::process::exit(2);
+    }
+    let Some(d) = find(year, day) else {
+        eprintln!("Day {day} of {year} is not implemented");
+        std::process::exit(1);
+    };
+    let source = std::env::args()
+        .nth(1)
+        .map(|arg| Source::from(arg.as_str()))
+        .unwrap_or_else(|| Source::default_for(year, day));
+    if !print_day(d, &source, &Options::default()) {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{Error, Result},
+        input::Source,
+        parse::BadLines,
+        runner::{find, solve_day, solve_days, Configs, Day, Options, Report, Run},
+        solution::Solution,
+    };
+
+    struct Panicking;
+
+    impl Solution for Panicking {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 25;
+        type Input = ();
+        type Answer = u8;
+        type Config = ();
+
+        fn parse(_input: &str) -> Result<()> {
+            panic!("assumption broken")
+        }
+
+        fn part_one(_input: &()) -> Result<u8> {
+            Ok(0)
+        }
+
+        fn part_two(_input: &()) -> Result<u8> {
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn test_solve_days() {
+        let panicking = Day::new::<Panicking>();
+        let days: Vec<(&Day, Source)> = [1, 4, 6]
+            .into_iter()
+            .map(|d| (find(2023, d).unwrap(), Source::default_for(2023, d)))
+            .chain([(&panicking, Source::default_for(2023, 1))])
+            .collect();
+        for jobs in [1, 3] {
+            let reports = solve_days(&days, &Options::default(), jobs);
+            let days: Vec<u8> = reports.iter().map(|r| r.day).collect();
+            assert_eq!(days, vec![1, 4, 6, 25]);
+            for report in &reports[..3] {
+                assert!(report.is_solved(), "{report:?}");
+            }
+            assert_eq!(
+                reports[3].run,
+                Err(Error::Panic("assumption broken".to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_solved() {
+        let report = |answers| Report {
+            year: 2023,
+            day: 1,
+            source: Source::Stdin,
+            run: Ok(Run {
+                answers,
+                remarks: vec![None, None],
+                timings: None,
+            }),
+        };
+        assert!(report(vec![Ok("1".to_string()), Ok("2".to_string())]).is_solved());
+        let failed = report(vec![
+            Ok("1".to_string()),
+            Err(Error::Validation("line 2 has no digit".to_string())),
+        ]);
+        assert!(!failed.is_solved());
+    }
+
+    #[test]
+    fn test_bad_lines() {
+        let day2 = find(2023, 2).unwrap();
+        let input = "Game 1: 1 red\nGame 2: 1 rod\nGame 3: 1 blue\n";
+        let run = day2.run(input, &Options::default());
+        assert!(matches!(run, Err(Error::Parse(e)) if e.line == 2));
+
+        let lenient = Options {
+            bad_lines: BadLines::Skip,
+            ..Options::default()
+        };
+        let answers = vec![Ok("4".to_string()), Ok("0".to_string())];
+        assert_eq!(
+            day2.run(input, &lenient).map(|r| r.answers),
+            Ok(answers.clone())
+        );
+        let path =
+            std::env::temp_dir().join(format!("aoc2023-bad-lines-{}.txt", std::process::id()));
+        std::fs::write(&path, input).unwrap();
+        let streamed = Options {
+            stream: true,
+            ..lenient
+        };
+        let report = solve_day(day2, &Source::Path(path.clone()), &streamed);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(report.run.map(|r| r.answers), Ok(answers));
+    }
+
+    #[test]
+    fn test_configs() {
+        let mut configs = Configs::default();
+        assert_eq!(configs.get::<u8>(), None);
+        configs.set(1u8);
+        configs.set("words");
+        configs.set(2u8);
+        assert_eq!(configs.get::<u8>(), Some(&2));
+        assert_eq!(configs.get::<&str>(), Some(&"words"));
+        assert_eq!(configs.get::<u16>(), None);
+    }
+}
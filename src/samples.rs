@@ -0,0 +1,177 @@
+//! The sample inputs of the puzzles and their expected answers.
+//!
+//! A sample input is a file `decN_sample*.txt` of the data directory of its year, e.g.
+//! `data/2023/dec5_sample.txt` or `data/2023/dec1_sample2.txt`. Its expected answers are in a
+//! file with the same name and the `.answers` extension, with one line per known part:
+//!
+//! ```text
+//! part 1: 35
+//! part 2: 46
+//! ```
+//!
+//! A part without an expected answer is not solved, as the samples of some puzzles only make
+//! sense for one part.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    input::year_dir,
+    runner::{self, Part},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub path: PathBuf,
+    /// The expected answer of each known part.
+    pub answers: Vec<(Part, String)>,
+}
+
+impl Sample {
+    /// Load the expected answers of the sample input at `path`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let answers_path = path.with_extension("answers");
+        let content = fs::read_to_string(&answers_path)
+            .map_err(|e| format!("{}: {e}", answers_path.display()))?;
+        let answers =
+            parse_answers(&content).map_err(|e| format!("{}: {e}", answers_path.display()))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            answers,
+        })
+    }
+
+    /// Solve the sample with the registered solver of a day of `year` and compare the answers to
+    /// the expected ones.
+    pub fn check(&self, year: u16, day: u8) -> Result<(), String> {
+        let solver = runner::find(year, day)
+            .ok_or_else(|| format!("day {day} of {year} is not implemented"))?;
+        let input =
+            fs::read_to_string(&self.path).map_err(|e| format!("{}: {e}", self.path.display()))?;
+        let parts: Vec<Part> = self.answers.iter().map(|(p, _)| *p).collect();
+        let answers = solver
+            .solve(&input, &parts)
+            .map_err(|e| e.in_file(self.path.display()).to_string())?;
+        let errors: Vec<String> = self
+            .answers
+            .iter()
+            .zip(answers)
+            .filter_map(|((part, expected), answer)| {
+                let answer = match answer {
+                    Ok(answer) if &answer == expected => return None,
+                    Ok(answer) => answer,
+                    Err(e) => e.to_string(),
+                };
+                Some(format!(
+                    "{} part {}: got {answer}, expected {expected}",
+                    self.path.display(),
+                    part.number()
+                ))
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("\n"))
+        }
+    }
+}
+
+fn parse_answers(content: &str) -> Result<Vec<(Part, String)>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(ix, l)| {
+            let answer = match l.split_once(':') {
+                Some(("part 1", a)) => (Part::One, a.trim().to_string()),
+                Some(("part 2", a)) => (Part::Two, a.trim().to_string()),
+                _ => return Err(format!("line {}: expected `part N: answer`", ix + 1)),
+            };
+            Ok(answer)
+        })
+        .collect()
+}
+
+/// The sample inputs of a day of `year`, with or without expected answers, ordered by file name.
+pub fn sample_paths(year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let dir = year_dir(year);
+    let prefix = format!("dec{day}_sample");
+    let entries = fs::read_dir(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            let name = p.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+            name.starts_with(&prefix) && name.ends_with(".txt")
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// The sample inputs of a day of `year` that have expected answers, ordered by file name.
+pub fn samples(year: u16, day: u8) -> Result<Vec<Sample>, String> {
+    sample_paths(year, day)?
+        .iter()
+        .filter(|p| p.with_extension("answers").exists())
+        .map(|p| Sample::load(p))
+        .collect()
+}
+
+/// Check every sample of a day of `year` that has expected answers.
+pub fn check_day(year: u16, day: u8) -> Result<(), String> {
+    let errors: Vec<String> = samples(year, day)?
+        .iter()
+        .filter_map(|s| s.check(year, day).err())
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        runner::{self, Part},
+        samples::{check_day, parse_answers, samples},
+    };
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("part 1: 35\npart 2: 46\n"),
+            Ok(vec![
+                (Part::One, "35".to_string()),
+                (Part::Two, "46".to_string())
+            ])
+        );
+        assert_eq!(
+            parse_answers("part 2: 281"),
+            Ok(vec![(Part::Two, "281".to_string())])
+        );
+        assert!(parse_answers("part 3: 1").is_err());
+    }
+
+    #[test]
+    fn test_samples() {
+        let samples = samples(2023, 5).unwrap();
+        assert_eq!(samples.len(), 1);
+        assert!(samples[0].path.ends_with("dec5_sample.txt"));
+    }
+
+    /// The answers to all the samples of every registered day of 2023, so that a day added with
+    /// `new-day` is checked as soon as its samples are.
+    #[test]
+    fn test_sample_answers() {
+        let errors: Vec<String> = runner::days(2023)
+            .iter()
+            .filter_map(|d| check_day(2023, d.day).err())
+            .collect();
+        assert!(errors.is_empty(), "{}", errors.join("\n"));
+    }
+}
@@ -0,0 +1,204 @@
+//! Generation of the files of a new day.
+//!
+//! For day N of year Y, [`new_day`] creates from the templates of `templates/`:
+//! - the module `src/yY/dayN.rs`, with a parser skeleton, a
+//!   [`Solution`](crate::solution::Solution) stub and a test module,
+//! - the binary `src/bin/yY_dayN.rs`,
+//! - empty `data/Y/decN.txt`, `data/Y/decN_sample.txt` and `data/Y/decN_sample.answers` files,
+//!
+//! then declares the module and registers the day in the module of the year, `src/yY.rs`, which
+//! must already exist.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const BIN_TEMPLATE: &str = include_str!("../templates/bin.rs");
+
+/// The placeholders replaced by the year and the day number in the templates.
+const YEAR_PLACEHOLDER: &str = "__YEAR__";
+const DAY_PLACEHOLDER: &str = "__DAY__";
+
+/// Generate the files of a day of `year` in the crate at `root` and register it. Return the
+/// created and modified files.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day {day}, expected 1 to 25"));
+    }
+    let module = root.join(format!("src/y{year}/day{day}.rs"));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+
+    // Compute every edit first so that nothing is written if a registration point is missing.
+    let year_path = root.join(format!("src/y{year}.rs"));
+    if !year_path.exists() {
+        return Err(format!(
+            "{}: no module for {year}, create it and list it in `runner::days` first",
+            year_path.display()
+        ));
+    }
+    let year_module = read(&year_path)?;
+    let year_module = register_module(&year_module, day)
+        .ok_or_else(|| format!("{}: no `pub mod dayN;` declaration", year_path.display()))?;
+    let year_module = register_day(&year_module, day)
+        .ok_or_else(|| format!("{}: no registered day", year_path.display()))?;
+
+    let fill = |template: &str| {
+        template
+            .replace(YEAR_PLACEHOLDER, &year.to_string())
+            .replace(DAY_PLACEHOLDER, &day.to_string())
+    };
+    let mut files = vec![
+        (module, fill(DAY_TEMPLATE)),
+        (
+            root.join(format!("src/bin/y{year}_day{day}.rs")),
+            fill(BIN_TEMPLATE),
+        ),
+    ];
+    for data in [
+        format!("dec{day}.txt"),
+        format!("dec{day}_sample.txt"),
+        format!("dec{day}_sample.answers"),
+    ] {
+        let path = root.join("data").join(year.to_string()).join(data);
+        if !path.exists() {
+            files.push((path, String::new()));
+        }
+    }
+    files.push((year_path, year_module));
+
+    for (path, content) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        }
+        fs::write(path, content).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+    Ok(files.into_iter().map(|(p, _)| p).collect())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// The day number at the start of `s`, e.g. 12 in "12::Day12,".
+fn leading_day(s: &str) -> Option<u8> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    s[..end].parse().ok()
+}
+
+/// Insert `line` among the lines of `content` which are for a day, as given by `line_day`, so
+/// that the days stay in order.
+fn insert_line(
+    content: &str,
+    day: u8,
+    line: &str,
+    line_day: impl Fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let pos = lines
+        .iter()
+        .rposition(|l| line_day(l).is_some_and(|d| d < day))
+        .map(|p| p + 1)
+        .or_else(|| lines.iter().position(|l| line_day(l).is_some()))?;
+    lines.insert(pos, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Declare `pub mod dayN;` in the module of a year.
+fn register_module(lib: &str, day: u8) -> Option<String> {
+    insert_line(lib, day, &format!("pub mod day{day};"), |l| {
+        leading_day(l.strip_prefix("pub mod day")?)
+    })
+}
+
+/// Import the solution of the day and add it to `DAYS` in the module of a year.
+fn register_day(year_module: &str, day: u8) -> Option<String> {
+    let imported = insert_line(year_module, day, &format!("use day{day}::Day{day};"), |l| {
+        leading_day(l.strip_prefix("use day")?)
+    })?;
+    insert_line(
+        &imported,
+        day,
+        &format!("    Day::new::<Day{day}>(),"),
+        |l| leading_day(l.strip_prefix("    Day::new::<Day")?),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::scaffold::{new_day, register_day, register_module};
+
+    const YEAR_MODULE: &str = "//! The puzzles of Advent of Code 2023.
+
+pub mod day1;
+pub mod day2;
+pub mod day10;
+
+use crate::runner::Day;
+use day1::Day1;
+use day10::Day10;
+use day2::Day2;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<Day1>(),
+    Day::new::<Day2>(),
+    Day::new::<Day10>(),
+];
+";
+
+    #[test]
+    fn test_register_module() {
+        assert!(register_module(YEAR_MODULE, 3)
+            .unwrap()
+            .contains("pub mod day1;\npub mod day2;\npub mod day3;\npub mod day10;\n"));
+        assert_eq!(
+            register_module("pub mod day2;\n", 1),
+            Some("pub mod day1;\npub mod day2;\n".to_string())
+        );
+        assert_eq!(register_module("pub mod input;\n", 3), None);
+    }
+
+    #[test]
+    fn test_register_day() {
+        let module = register_day(YEAR_MODULE, 3).unwrap();
+        assert!(module.contains("use day2::Day2;\nuse day3::Day3;\n\npub const"));
+        assert!(module.contains(
+            "    Day::new::<Day2>(),\n    Day::new::<Day3>(),\n    Day::new::<Day10>(),"
+        ));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc2023-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/y2023.rs"), YEAR_MODULE).unwrap();
+
+        let files = new_day(&root, 2023, 3).unwrap();
+        assert_eq!(files.len(), 6);
+        let module = fs::read_to_string(root.join("src/y2023/day3.rs")).unwrap();
+        assert!(module.contains(
+            "impl Solution for Day3 {\n    const YEAR: u16 = 2023;\n    const DAY: u8 = 3;"
+        ));
+        assert!(module.contains("use crate::y2023::day3::line;"));
+        let bin = fs::read_to_string(root.join("src/bin/y2023_day3.rs")).unwrap();
+        assert!(bin.contains("main_for_day(2023, 3)"));
+        assert!(root.join("data/2023/dec3_sample.answers").exists());
+        assert!(fs::read_to_string(root.join("src/y2023.rs"))
+            .unwrap()
+            .contains("pub mod day3;"));
+
+        assert!(new_day(&root, 2023, 3)
+            .unwrap_err()
+            .ends_with("already exists"));
+        assert!(new_day(&root, 2023, 26).is_err());
+        assert!(new_day(&root, 2024, 1)
+            .unwrap_err()
+            .contains("no module for 2024"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,48 @@
+//! The interface shared by the solvers of every day.
+
+use std::fmt::Display;
+
+use crate::{error::Result, parse::BadLines};
+
+/// A day's puzzle solver.
+///
+/// The puzzle input is parsed once with [`Solution::parse`], or [`Solution::parse_with`] for a
+/// solver with settings, then each part is solved from the parsed input.
+pub trait Solution {
+    /// The year of the puzzle, e.g. 2023.
+    const YEAR: u16;
+
+    /// The day of the puzzle (1 to 25).
+    const DAY: u8;
+
+    /// The parsed puzzle input.
+    type Input;
+
+    /// The answer to either part of the puzzle.
+    type Answer: Display;
+
+    /// The settings of the solver, e.g. given on the command line, or `()` if it has none.
+    type Config: Default + Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parse the input for the solver set up with `config`, handling the lines which cannot be
+    /// parsed as set by `bad_lines`. Both are ignored by default.
+    fn parse_with(
+        input: &str,
+        _config: &Self::Config,
+        _bad_lines: BadLines,
+    ) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer>;
+
+    /// A remark to report with an answer, e.g. the lines of the input it leaves out. None by
+    /// default.
+    fn remark(_answer: &Self::Answer) -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,123 @@
+//! A store of the puzzle inputs of several users, laid out by year, day and user:
+//!
+//! ```text
+//! inputs/2023/05/alice.txt
+//! inputs/2023/05/bob.txt
+//! ```
+//!
+//! Solving a day on every stored input catches the solutions which only work on one input.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The directory of the store, relative to the working directory or to the crate root.
+pub const STORE_DIR: &str = "inputs";
+
+pub struct Store {
+    pub root: PathBuf,
+}
+
+impl Default for Store {
+    /// The `inputs` directory in the working directory if there is one, else in the crate root.
+    fn default() -> Self {
+        let dir = Path::new(STORE_DIR);
+        let root = if dir.is_dir() {
+            dir.to_path_buf()
+        } else {
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(STORE_DIR)
+        };
+        Self { root }
+    }
+}
+
+/// Check that a user name can be used as a file name.
+fn check_user(user: &str) -> Result<(), String> {
+    let valid = !user.is_empty()
+        && !user.starts_with('.')
+        && user
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "invalid user name `{user}`, expected letters, digits, `-`, `_` or `.`"
+        ))
+    }
+}
+
+impl Store {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The directory of the inputs of a day.
+    pub fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.root.join(year.to_string()).join(format!("{day:02}"))
+    }
+
+    /// The path of the input of `user` for a day.
+    pub fn path(&self, year: u16, day: u8, user: &str) -> Result<PathBuf, String> {
+        check_user(user)?;
+        Ok(self.day_dir(year, day).join(format!("{user}.txt")))
+    }
+
+    /// The stored inputs of a day, as (user, path), ordered by user.
+    pub fn inputs(&self, year: u16, day: u8) -> Result<Vec<(String, PathBuf)>, String> {
+        let dir = self.day_dir(year, day);
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(format!("{}: {e}", dir.display())),
+        };
+        let mut inputs: Vec<(String, PathBuf)> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+            .filter_map(|p| {
+                let user = p.file_stem()?.to_str()?.to_string();
+                Some((user, p))
+            })
+            .collect();
+        inputs.sort();
+        Ok(inputs)
+    }
+
+    /// Store the input of `user` for a day, and return its path.
+    pub fn add(&self, year: u16, day: u8, user: &str, input: &str) -> Result<PathBuf, String> {
+        let path = self.path(year, day, user)?;
+        let dir = self.day_dir(year, day);
+        fs::create_dir_all(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        fs::write(&path, input).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::store::Store;
+
+    #[test]
+    fn test_store() {
+        let root = std::env::temp_dir().join(format!("aoc2023-store-{}", std::process::id()));
+        let store = Store::new(&root);
+        assert_eq!(store.inputs(2023, 5), Ok(vec![]));
+
+        let bob = store.add(2023, 5, "bob", "seeds: 1 2\n").unwrap();
+        let alice = store.add(2023, 5, "alice", "seeds: 3 4\n").unwrap();
+        store.add(2023, 6, "alice", "Time: 7\n").unwrap();
+        assert!(alice.ends_with("2023/05/alice.txt"));
+        assert_eq!(
+            store.inputs(2023, 5),
+            Ok(vec![("alice".to_string(), alice), ("bob".to_string(), bob)])
+        );
+
+        assert!(store.path(2023, 5, "../bob").is_err());
+        assert!(store.path(2023, 5, "").is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
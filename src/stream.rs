@@ -0,0 +1,187 @@
+//! Solving the puzzles made of one record per line from any reader, a line at a time.
+//!
+//! A [`LineSolution`] keeps running answers which are updated with each line, so that inputs
+//! much larger than the memory, or read from a pipe, can be solved.
+
+use std::io::BufRead;
+
+use crate::{
+    error::{Error, ParseError, Result},
+    input::normalize_line,
+    parse::BadLines,
+    runner::{Configs, Part, Run},
+    solution::Solution,
+};
+
+/// A solution whose answers can be computed line by line.
+pub trait LineSolution: Solution {
+    /// The running answers.
+    type State: Default;
+
+    /// The running answers before the first line, for the solver set up with `config`.
+    fn start(_config: &Self::Config) -> Self::State {
+        Self::State::default()
+    }
+
+    /// Update the running answers with the line number `ln` (from 1).
+    fn update(state: &mut Self::State, ln: usize, line: &str) -> Result<()>;
+
+    fn part_one_answer(state: &Self::State) -> Result<Self::Answer>;
+
+    fn part_two_answer(state: &Self::State) -> Result<Self::Answer>;
+}
+
+/// Read every [normalized](normalize_line) line of `reader` into the running answers of `S`,
+/// with the default settings, failing with the errors of the bad lines.
+pub fn read<S: LineSolution>(reader: impl BufRead) -> Result<S::State> {
+    read_with::<S>(reader, &S::Config::default(), BadLines::Fail)
+}
+
+/// Read every line of `reader` into the running answers of `S` set up with `config`, as
+/// [`read`] does, the bad lines being reported or skipped as set by `bad_lines`.
+pub fn read_with<S: LineSolution>(
+    mut reader: impl BufRead,
+    config: &S::Config,
+    bad_lines: BadLines,
+) -> Result<S::State> {
+    let mut state = S::start(config);
+    let mut errors = Vec::new();
+    let mut line = String::new();
+    let mut blank_lines = Vec::new();
+    for ln in 1.. {
+        line.clear();
+        let n = reader
+            .read_line(&mut line)
+            .map_err(|e| Error::Input(format!("line {ln}: {e}")))?;
+        if n == 0 {
+            break;
+        }
+        let line = normalize_line(&line);
+        // The blank lines are only passed on when followed by another line, as the trailing
+        // ones are dropped by the normalization.
+        if line.is_empty() {
+            blank_lines.push(ln);
+            continue;
+        }
+        for blank in blank_lines.drain(..) {
+            update::<S>(&mut state, blank, "", &mut errors)?;
+        }
+        update::<S>(&mut state, ln, line, &mut errors)?;
+    }
+    bad_lines.handle(errors)?;
+    Ok(state)
+}
+
+/// Update `state` with a line, collecting its parse error if any.
+fn update<S: LineSolution>(
+    state: &mut S::State,
+    ln: usize,
+    line: &str,
+    errors: &mut Vec<ParseError>,
+) -> Result<()> {
+    match S::update(state, ln, line) {
+        Ok(()) => Ok(()),
+        Err(Error::Parse(mut e)) => {
+            e.line = ln;
+            errors.push(e);
+            Ok(())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Read `reader` with the settings of `S` in `configs`, or the default ones, and solve the given
+/// parts, untimed.
+pub fn solve<S: LineSolution>(
+    reader: &mut dyn BufRead,
+    parts: &[Part],
+    configs: &Configs,
+    bad_lines: BadLines,
+) -> Result<Run> {
+    let default = S::Config::default();
+    let config = configs.get::<S::Config>().unwrap_or(&default);
+    let state = read_with::<S>(reader, config, bad_lines)?;
+    let mut answers = Vec::with_capacity(parts.len());
+    let mut remarks = Vec::with_capacity(parts.len());
+    for part in parts {
+        let answer = match part {
+            Part::One => S::part_one_answer(&state),
+            Part::Two => S::part_two_answer(&state),
+        };
+        remarks.push(answer.as_ref().ok().and_then(S::remark));
+        answers.push(answer.map(|a| a.to_string()));
+    }
+    Ok(Run {
+        answers,
+        remarks,
+        timings: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Cursor};
+
+    use crate::{
+        error::Error,
+        input::Source,
+        parse::BadLines,
+        runner::{self, Configs, Part},
+        stream::{read, solve, LineSolution},
+        y2023::{day1::Day1, day2::Day2, day4::Day4},
+    };
+
+    /// Streaming the default input of `day`, through a small buffer, gives the same answers as
+    /// reading it whole.
+    fn check<S: LineSolution>() {
+        let source = Source::default_for(S::YEAR, S::DAY);
+        let input = source.load().unwrap();
+        let mut reader = BufReader::with_capacity(16, input.as_bytes());
+        assert_eq!(
+            solve::<S>(&mut reader, &Part::ALL, &Configs::default(), BadLines::Fail)
+                .unwrap()
+                .answers,
+            runner::find(S::YEAR, S::DAY)
+                .unwrap()
+                .solve(&input, &Part::ALL)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_same_answers() {
+        check::<Day1>();
+        check::<Day2>();
+        check::<Day4>();
+    }
+
+    #[test]
+    fn test_normalized() {
+        let sums = read::<Day1>(Cursor::new("\u{feff}a1b2 \r\nthree4\r\n\r\n")).unwrap();
+        assert_eq!(Day1::part_one_answer(&sums).map(|t| t.sum), Ok(56));
+        assert_eq!(Day1::part_two_answer(&sums).map(|t| t.sum), Ok(46));
+    }
+
+    #[test]
+    fn test_errors() {
+        let Err(Error::Parse(e)) = read::<Day2>(Cursor::new("Game 1: 1 red\nGame 2: 1 rod\n"))
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (2, 11));
+        let Err(Error::Parse(e)) = read::<Day2>(Cursor::new("Game 1: 1 red\n\nGame 3: 1 red\n"))
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (2, 1));
+
+        let pile = read::<Day4>(Cursor::new("Card 1: 1 2 | 1\nCard 2: 1 2 | 1 2\n")).unwrap();
+        assert_eq!(
+            Day4::part_two_answer(&pile),
+            Err(Error::Validation(
+                "card 2 wins copies of cards past the last one".to_string()
+            ))
+        );
+        assert_eq!(Day4::part_one_answer(&pile), Ok(3));
+    }
+}
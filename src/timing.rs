@@ -0,0 +1,62 @@
+//! Summary statistics of repeated timings.
+
+use std::{fmt::Display, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarize a **non-empty** list of timings.
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty());
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        Self {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.2?}, median {:.2?}, max {:.2?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::timing::Stats;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Stats::new(&[ms(3), ms(1), ms(2)]),
+            Stats {
+                min: ms(1),
+                median: ms(2),
+                max: ms(3)
+            }
+        );
+        assert_eq!(Stats::new(&[ms(4), ms(1), ms(2), ms(8)]).median, ms(3));
+        assert_eq!(Stats::new(&[ms(5)]).max, ms(5));
+    }
+}
@@ -0,0 +1,131 @@
+//! The words spelling out the digits, in several languages.
+//!
+//! Besides the built-in vocabularies, a vocabulary can be read from a file with one word and
+//! its digit per line, separated by whitespace. Blank lines and lines starting with `#` are
+//! ignored, and a digit may have several words:
+//!
+//! ```text
+//! # Dutch
+//! nul 0
+//! een 1
+//! één 1
+//! ```
+
+use std::{fs, path::Path};
+
+/// The names of the built-in vocabularies, the first one being the default.
+pub const BUILT_IN: [&str; 3] = ["english", "french", "german"];
+
+const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const FRENCH: [&str; 10] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const GERMAN: [&str; 10] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+
+/// Words and the digits they spell out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vocabulary {
+    pub words: Vec<(String, u32)>,
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::built_in(BUILT_IN[0]).unwrap()
+    }
+}
+
+impl Vocabulary {
+    /// The built-in vocabulary with the given name, if any.
+    pub fn built_in(name: &str) -> Option<Self> {
+        let words = match name {
+            "english" => ENGLISH,
+            "french" => FRENCH,
+            "german" => GERMAN,
+            _ => return None,
+        };
+        Some(Self {
+            words: words.iter().map(|w| w.to_string()).zip(0..).collect(),
+        })
+    }
+
+    /// Parse a vocabulary file.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let words: Vec<(String, u32)> = content
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+            .map(|(ix, l)| word(l).ok_or_else(|| format!("line {}: invalid word `{l}`", ix + 1)))
+            .collect::<Result<_, _>>()?;
+        if words.is_empty() {
+            return Err("no words".to_string());
+        }
+        Ok(Self { words })
+    }
+
+    /// Load the vocabulary file at `path`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Self::parse(&content).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// The built-in vocabulary named `arg`, or else the vocabulary file at that path.
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        match Self::built_in(arg) {
+            Some(vocabulary) => Ok(vocabulary),
+            None => Self::load(Path::new(arg)),
+        }
+    }
+}
+
+fn word(line: &str) -> Option<(String, u32)> {
+    let mut fields = line.split_whitespace();
+    let word = fields.next()?.to_string();
+    let digit = fields.next()?.parse().ok().filter(|d| *d < 10)?;
+    if fields.next().is_some() {
+        return None;
+    }
+    Some((word, digit))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vocabulary::{Vocabulary, BUILT_IN};
+
+    #[test]
+    fn test_built_in() {
+        for name in BUILT_IN {
+            let vocabulary = Vocabulary::built_in(name).unwrap();
+            assert_eq!(vocabulary.words.len(), 10);
+        }
+        assert_eq!(Vocabulary::default().words[3], ("three".to_string(), 3));
+        assert_eq!(Vocabulary::built_in("klingon"), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Vocabulary::parse("# Dutch\n\nnul 0\neen 1\néén 1\n"),
+            Ok(Vocabulary {
+                words: vec![
+                    ("nul".to_string(), 0),
+                    ("een".to_string(), 1),
+                    ("één".to_string(), 1)
+                ]
+            })
+        );
+        assert_eq!(
+            Vocabulary::parse("tien 10\n"),
+            Err("line 1: invalid word `tien 10`".to_string())
+        );
+        assert_eq!(
+            Vocabulary::parse("twee\n"),
+            Err("line 1: invalid word `twee`".to_string())
+        );
+        assert!(Vocabulary::parse("# nothing\n").is_err());
+        assert!(Vocabulary::from_arg("data/missing.txt").is_err());
+    }
+}
@@ -0,0 +1,166 @@
+//! Re-solving a day whenever its input or sample files change.
+//!
+//! The files are polled, as comparing their modification times and sizes is enough for files
+//! edited by hand.
+
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    input::Source,
+    output,
+    runner::{self, Day, Options, Part},
+    samples::{sample_paths, Sample},
+};
+
+/// The files of a day of `year`: its default input, its sample inputs and their expected
+/// answers.
+pub fn watched_files(year: u16, day: u8) -> Vec<PathBuf> {
+    let mut files = vec![];
+    if let Source::Path(path) = Source::default_for(year, day) {
+        files.push(path);
+    }
+    for sample in sample_paths(year, day).unwrap_or_default() {
+        files.push(sample.with_extension("answers"));
+        files.push(sample);
+    }
+    files
+}
+
+/// The state of a file: its modification time and size, or `None` if it cannot be read.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &PathBuf) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Tells when a set of files changes.
+pub struct Watcher {
+    files: Box<dyn Fn() -> Vec<PathBuf>>,
+    stamps: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+    /// Watch the files listed by `files`, which may list other files over time.
+    pub fn new(files: impl Fn() -> Vec<PathBuf> + 'static) -> Self {
+        Self {
+            files: Box::new(files),
+            stamps: vec![],
+        }
+    }
+
+    /// Whether a file was added, removed or modified since the last poll. The first poll
+    /// always reports a change.
+    pub fn poll(&mut self) -> bool {
+        let stamps: Vec<(PathBuf, Stamp)> = (self.files)()
+            .into_iter()
+            .map(|f| {
+                let s = stamp(&f);
+                (f, s)
+            })
+            .collect();
+        let changed = self.stamps.is_empty() || stamps != self.stamps;
+        self.stamps = stamps;
+        changed
+    }
+}
+
+/// Solve both parts of `day` on each of its inputs, comparing the answers of the samples with
+/// the expected ones.
+pub fn render(day: &Day) -> String {
+    let options = Options::default();
+    let mut out = String::new();
+    let mut inputs = vec![Source::default_for(day.year, day.day)];
+    inputs.extend(
+        sample_paths(day.year, day.day)
+            .unwrap_or_default()
+            .into_iter()
+            .map(Source::Path),
+    );
+    for source in inputs {
+        out += &format!("== {source} ==\n");
+        let report = runner::solve_day(day, &source, &options);
+        out += &output::text(&report, &options.parts);
+        let Source::Path(path) = &source else {
+            continue;
+        };
+        if !path.with_extension("answers").exists() {
+            continue;
+        }
+        match (Sample::load(path), &report.run) {
+            (Err(e), _) => out += &format!("\t{e}\n"),
+            (Ok(sample), Ok(run)) => {
+                for (part, expected) in &sample.answers {
+                    let ix = Part::ALL.iter().position(|p| p == part).unwrap();
+                    match &run.answers[ix] {
+                        Ok(answer) if answer == expected => {
+                            out += &format!("\tpart {}: pass\n", part.number())
+                        }
+                        _ => {
+                            out += &format!("\tpart {}: fail, expected {expected}\n", part.number())
+                        }
+                    }
+                }
+            }
+            (Ok(_), Err(_)) => {}
+        }
+    }
+    out
+}
+
+/// Clear the terminal and print the answers of `day` each time one of its files changes,
+/// polling every `interval`. Never returns.
+pub fn watch(day: &Day, interval: Duration) -> ! {
+    let (year, number) = (day.year, day.day);
+    let mut watcher = Watcher::new(move || watched_files(year, number));
+    loop {
+        if watcher.poll() {
+            // Clear the screen and move the cursor to the top left corner.
+            print!("\x1b[2J\x1b[H");
+            println!("Watching day {number} of {year}, press Ctrl-C to stop.\n");
+            print!("{}", render(day));
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{runner::find, watch::render, watch::Watcher};
+
+    #[test]
+    fn test_poll() {
+        let dir = std::env::temp_dir().join(format!("aoc2023-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("dec1.txt");
+        fs::write(&file, "1abc2\n").unwrap();
+        let (a, b) = (file.clone(), dir.join("dec1_sample.txt"));
+        let mut watcher = Watcher::new(move || vec![a.clone(), b.clone()]);
+
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+        fs::write(&file, "1abc2\npqr3stu8vwx\n").unwrap();
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+        fs::write(dir.join("dec1_sample.txt"), "").unwrap();
+        assert!(watcher.poll());
+        fs::remove_file(&file).unwrap();
+        assert!(watcher.poll());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render() {
+        let out = render(find(2023, 5).unwrap());
+        assert!(out.contains("dec5.txt ==\nAnswer for Day 5:\n\tpart 1: 535088217\n"));
+        assert!(out.contains("dec5_sample.txt ==\n"));
+        assert!(out.contains("\tpart 1: pass\n\tpart 2: pass\n"));
+    }
+}
@@ -0,0 +1,25 @@
+//! The puzzles of Advent of Code 2023.
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+
+use crate::runner::Day;
+use day1::Day1;
+use day2::Day2;
+use day3::Day3;
+use day4::Day4;
+use day5::Day5;
+use day6::Day6;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<Day1>().streaming::<Day1>(),
+    Day::new::<Day2>().streaming::<Day2>(),
+    Day::new::<Day3>(),
+    Day::new::<Day4>().streaming::<Day4>(),
+    Day::new::<Day5>(),
+    Day::new::<Day6>(),
+];
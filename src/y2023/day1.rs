@@ -0,0 +1,606 @@
+use std::{cmp::Reverse, fmt::Display, str::FromStr, sync::Arc};
+
+use crate::{
+    error::{Error, Result},
+    matcher::{Match, Matcher},
+    numbers,
+    parse::BadLines,
+    solution::Solution,
+    stream::LineSolution,
+    vocabulary::Vocabulary,
+};
+
+/// The settings of the solver.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// What makes the calibration values.
+    pub mode: Mode,
+    /// What to do with the lines with no digit.
+    pub no_digit: NoDigit,
+    /// The digits of part two, written with a digit or spelled out.
+    pub digits: Arc<Matcher<u32>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new(&Vocabulary::default())
+    }
+}
+
+impl Config {
+    /// Spell out the digits with the words of `vocabulary` in part two, in [`Mode::Digits`],
+    /// failing on the lines with no digit.
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        Self {
+            mode: Mode::Digits,
+            no_digit: NoDigit::Fail,
+            digits: Arc::new(digit_matcher(vocabulary)),
+        }
+    }
+}
+
+/// The lines of the calibration document, and the settings to read them with.
+#[derive(Debug, Clone)]
+pub struct Document {
+    pub config: Config,
+    pub lines: Vec<String>,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    type Input = Document;
+    type Answer = Total;
+    type Config = Config;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Config::default(), BadLines::Fail)
+    }
+
+    fn parse_with(input: &str, config: &Config, _bad_lines: BadLines) -> Result<Self::Input> {
+        Ok(Document {
+            config: config.clone(),
+            lines: input.lines().map(String::from).collect(),
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Total> {
+        total(input, written_values)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Total> {
+        total(input, spelled_values)
+    }
+
+    fn remark(total: &Total) -> Option<String> {
+        total.remark()
+    }
+}
+
+/// What to do with the lines with no digit, or with no number in [`Mode::Numbers`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NoDigit {
+    /// Fail, giving the first such line.
+    #[default]
+    Fail,
+    /// Leave the line out of the sum.
+    Skip,
+    /// Count the calibration value of the line as 0. The sum is the same as when skipping the
+    /// line, only the report differs.
+    Zero,
+}
+
+impl FromStr for NoDigit {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "fail" => Ok(NoDigit::Fail),
+            "skip" => Ok(NoDigit::Skip),
+            "zero" => Ok(NoDigit::Zero),
+            _ => Err(format!(
+                "unknown policy `{s}`, expected `fail`, `skip` or `zero`"
+            )),
+        }
+    }
+}
+
+/// The sum of the calibration values of the lines read so far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Total {
+    pub policy: NoDigit,
+    pub mode: Mode,
+    pub sum: u64,
+    /// The number of lines with no digit, skipped or counted as 0.
+    pub missing: usize,
+}
+
+impl Total {
+    pub fn new(policy: NoDigit, mode: Mode) -> Self {
+        Self {
+            policy,
+            mode,
+            sum: 0,
+            missing: 0,
+        }
+    }
+
+    /// Add the calibration value of the line at index `ix`, made of `values`.
+    pub fn add(&mut self, values: &[u64], ix: usize) -> Result<()> {
+        if values.is_empty() && self.policy != NoDigit::Fail {
+            self.missing += 1;
+        } else {
+            self.sum += calibration_value(values, ix, self.mode)?;
+        }
+        Ok(())
+    }
+
+    /// How many lines with no digit were skipped or counted as 0, if any.
+    pub fn remark(&self) -> Option<String> {
+        let (n, what) = (self.missing, self.mode.noun());
+        if n == 0 {
+            return None;
+        }
+        let lines = if n == 1 { "line" } else { "lines" };
+        match self.policy {
+            NoDigit::Fail => None,
+            NoDigit::Skip => Some(format!("skipped {n} {lines} with no {what}")),
+            NoDigit::Zero => Some(format!("counted {n} {lines} with no {what} as 0")),
+        }
+    }
+}
+
+/// The sum only, the lines left out being given by the [remark](Total::remark).
+impl Display for Total {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.sum)
+    }
+}
+
+/// The total of the calibration values of the lines of `document`, made of the `values` of each
+/// line.
+fn total(document: &Document, values: impl Fn(&str, &Config) -> Vec<u64>) -> Result<Total> {
+    let config = &document.config;
+    let mut total = Total::new(config.no_digit, config.mode);
+    for (ix, line) in document.lines.iter().enumerate() {
+        total.add(&values(line, config), ix)?;
+    }
+    Ok(total)
+}
+
+/// The running totals of the calibration values of each part, or the first error of each, and
+/// the settings to read the lines with.
+#[derive(Debug, Clone)]
+pub struct Sums {
+    pub config: Config,
+    pub part_one: Result<Total>,
+    pub part_two: Result<Total>,
+}
+
+impl Default for Sums {
+    fn default() -> Self {
+        Day1::start(&Config::default())
+    }
+}
+
+fn add(total: &mut Result<Total>, values: &[u64], ix: usize) {
+    if let Ok(t) = total {
+        if let Err(e) = t.add(values, ix) {
+            *total = Err(e);
+        }
+    }
+}
+
+impl LineSolution for Day1 {
+    type State = Sums;
+
+    fn start(config: &Config) -> Sums {
+        let total = Total::new(config.no_digit, config.mode);
+        Sums {
+            config: config.clone(),
+            part_one: Ok(total),
+            part_two: Ok(total),
+        }
+    }
+
+    fn update(sums: &mut Sums, ln: usize, line: &str) -> Result<()> {
+        add(
+            &mut sums.part_one,
+            &written_values(line, &sums.config),
+            ln - 1,
+        );
+        add(
+            &mut sums.part_two,
+            &spelled_values(line, &sums.config),
+            ln - 1,
+        );
+        Ok(())
+    }
+
+    fn part_one_answer(sums: &Sums) -> Result<Total> {
+        sums.part_one.clone()
+    }
+
+    fn part_two_answer(sums: &Sums) -> Result<Total> {
+        sums.part_two.clone()
+    }
+}
+
+/// What the calibration value of a line is made of.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// The first and last digits of the line, e.g. 13 for "1abc23".
+    #[default]
+    Digits,
+    /// The first and last numbers of the line, written one after the other, e.g. 123 for
+    /// "1abc23", or 235 for "twenty-three and five" in part two. The numbers are spelled out
+    /// in English whatever the vocabulary.
+    Numbers,
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "digits" => Ok(Mode::Digits),
+            "numbers" => Ok(Mode::Numbers),
+            _ => Err(format!(
+                "unknown mode `{s}`, expected `digits` or `numbers`"
+            )),
+        }
+    }
+}
+
+impl Mode {
+    /// What the calibration value is made of, in the singular.
+    fn noun(self) -> &'static str {
+        match self {
+            Mode::Digits => "digit",
+            Mode::Numbers => "number",
+        }
+    }
+}
+
+/// The digits or the numbers of a line, in the mode of `config`, written with digits.
+fn written_values(line: &str, config: &Config) -> Vec<u64> {
+    match config.mode {
+        Mode::Digits => digits(line).into_iter().map(u64::from).collect(),
+        Mode::Numbers => numbers::written(line),
+    }
+}
+
+/// The digits or the numbers of a line, in the mode of `config`, written with digits or spelled
+/// out.
+fn spelled_values(line: &str, config: &Config) -> Vec<u64> {
+    match config.mode {
+        Mode::Digits => parse_line_with(line, &config.digits)
+            .into_iter()
+            .map(u64::from)
+            .collect(),
+        Mode::Numbers => numbers::numbers(line),
+    }
+}
+
+/// The number written with the first and then the last value of the line at index `ix`, made
+/// of the values of `mode`.
+fn calibration_value(values: &[u64], ix: usize, mode: Mode) -> Result<u64> {
+    let (Some(&first), Some(&last)) = (values.first(), values.last()) else {
+        let what = mode.noun();
+        return Err(Error::Validation(format!("line {} has no {what}", ix + 1)));
+    };
+    10u64
+        .checked_pow(last.checked_ilog10().unwrap_or(0) + 1)
+        .and_then(|shift| first.checked_mul(shift))
+        .and_then(|value| value.checked_add(last))
+        .ok_or_else(|| {
+            Error::Validation(format!(
+                "line {}: calibration value {first}{last} is too large",
+                ix + 1
+            ))
+        })
+}
+
+/// The digits of a line, in order.
+pub fn digits(line: &str) -> Vec<u32> {
+    line.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+/// The matcher of the digits, written with a digit or with a word of `vocabulary`.
+pub fn digit_matcher(vocabulary: &Vocabulary) -> Matcher<u32> {
+    Matcher::new(
+        (0..10)
+            .map(|d| (d.to_string(), d))
+            .chain(vocabulary.words.iter().cloned()),
+    )
+}
+
+/// The digits of a line found by `digits`, e.g. built by [`digit_matcher`], in order. Spelled out
+/// digits may share letters, e.g. "twone" gives 2 and 1 in English.
+pub fn parse_line_with(input: &str, digits: &Matcher<u32>) -> Vec<u32> {
+    let mut matches: Vec<Match<u32>> = digits.find_iter(input).collect();
+    // A word may contain another one, which then ends first although it starts after.
+    matches.sort_by_key(|m| (m.start, Reverse(m.end)));
+    matches.iter().map(|m| *m.value).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::{
+        error::Error,
+        input,
+        numbers::numbers,
+        parse::BadLines,
+        runner::{self, Options, Part},
+        solution::Solution,
+        stream::{self, LineSolution},
+        vocabulary::Vocabulary,
+        y2023::day1::{
+            calibration_value, digit_matcher, digits, parse_line_with, Config, Day1, Mode, NoDigit,
+            Total,
+        },
+    };
+
+    /// The sum of a total, leaving out the lines with no digit.
+    fn sum(total: Result<Total, Error>) -> Result<u64, Error> {
+        total.map(|t| t.sum)
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let english = digit_matcher(&Vocabulary::default());
+        let parse_line = |line| parse_line_with(line, &english);
+        assert_eq!(parse_line("twoneighthree"), vec![2, 1, 8, 3]);
+        assert_eq!(parse_line("sevenine"), vec![7, 9]);
+        assert_eq!(parse_line("zoneight234"), vec![1, 8, 2, 3, 4]);
+        assert_eq!(parse_line("eighthree"), vec![8, 3]);
+        assert_eq!(parse_line("abc"), vec![]);
+    }
+
+    #[test]
+    fn test_vocabularies() {
+        let french = digit_matcher(&Vocabulary::built_in("french").unwrap());
+        assert_eq!(
+            parse_line_with("deuxneufuntrois", &french),
+            vec![2, 9, 1, 3]
+        );
+        assert_eq!(parse_line_with("zéroun7", &french), vec![0, 1, 7]);
+        let german = digit_matcher(&Vocabulary::built_in("german").unwrap());
+        assert_eq!(parse_line_with("zweinsiebenull", &german), vec![2, 1, 7, 0]);
+        assert_eq!(parse_line_with("twone", &german), vec![]);
+
+        // "ein" is in "eins", and "sieben" ends after "einsieben" starts.
+        let nested = Vocabulary::parse("ein 1\neins 1\neinsieben 8\nsieben 7\n").unwrap();
+        let nested = digit_matcher(&nested);
+        assert_eq!(parse_line_with("xeinsiebenx", &nested), vec![8, 1, 1, 7]);
+    }
+
+    #[test]
+    fn test_vocabulary_config() {
+        let document = "deux1neuf\nsept2two\n";
+        let french = Config::new(&Vocabulary::built_in("french").unwrap());
+        let input = Day1::parse_with(document, &french, BadLines::Fail).unwrap();
+        assert_eq!(sum(Day1::part_one(&input)), Ok(11 + 22));
+        assert_eq!(sum(Day1::part_two(&input)), Ok(29 + 72));
+        let sums =
+            stream::read_with::<Day1>(Cursor::new(document), &french, BadLines::Fail).unwrap();
+        assert_eq!(sum(Day1::part_one_answer(&sums)), Ok(11 + 22));
+        assert_eq!(sum(Day1::part_two_answer(&sums)), Ok(29 + 72));
+
+        // The words are English by default.
+        let input = Day1::parse(document).unwrap();
+        assert_eq!(sum(Day1::part_two(&input)), Ok(11 + 22));
+
+        let mut options = Options::default();
+        options.configs.set(french);
+        let answers = vec![Ok("33".to_string()), Ok("101".to_string())];
+        let run = runner::find(2023, 1).unwrap().run(document, &options);
+        assert_eq!(run.unwrap().answers, answers);
+        let mut reader = Cursor::new(document);
+        let streamed =
+            stream::solve::<Day1>(&mut reader, &Part::ALL, &options.configs, options.bad_lines);
+        assert_eq!(streamed.unwrap().answers, answers);
+    }
+
+    #[test]
+    fn test_one_digit() {
+        assert_eq!(digits("treb7uchet"), vec![7]);
+        let input = Day1::parse("treb7uchet\nabcone\n").unwrap();
+        assert_eq!(sum(Day1::part_two(&input)), Ok(77 + 11));
+    }
+
+    #[test]
+    fn test_no_digit() {
+        let input = Day1::parse("1abc2\nabc\n").unwrap();
+        assert_eq!(
+            sum(Day1::part_one(&input)),
+            Err(Error::Validation("line 2 has no digit".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_no_digit_policies() {
+        let lines = [vec![1, 2], vec![], vec![3], vec![]];
+        let totals: Vec<Result<Total, Error>> = [NoDigit::Fail, NoDigit::Skip, NoDigit::Zero]
+            .into_iter()
+            .map(|policy| {
+                let mut total = Total::new(policy, Mode::Digits);
+                for (ix, values) in lines.iter().enumerate() {
+                    total.add(values, ix)?;
+                }
+                Ok(total)
+            })
+            .collect();
+        assert_eq!(
+            totals[0],
+            Err(Error::Validation("line 2 has no digit".to_string()))
+        );
+        for (total, policy) in totals[1..].iter().zip([NoDigit::Skip, NoDigit::Zero]) {
+            assert_eq!(
+                total,
+                &Ok(Total {
+                    policy,
+                    mode: Mode::Digits,
+                    sum: 12 + 33,
+                    missing: 2
+                })
+            );
+        }
+        assert_eq!(
+            totals[1].as_ref().unwrap().remark().unwrap(),
+            "skipped 2 lines with no digit"
+        );
+        let mut total = Total::new(NoDigit::Zero, Mode::Numbers);
+        assert_eq!(total.remark(), None);
+        total.add(&[], 0).unwrap();
+        assert_eq!(
+            total.remark().unwrap(),
+            "counted 1 line with no number as 0"
+        );
+        assert_eq!("zero".parse(), Ok(NoDigit::Zero));
+        assert!("ignore".parse::<NoDigit>().is_err());
+    }
+
+    #[test]
+    fn test_no_digit_solver() {
+        // "four" only has a digit in part two.
+        let document = "1abc2\nabc\n3x\n\nfour\n";
+        let skipped = [
+            "skipped 3 lines with no digit",
+            "skipped 2 lines with no digit",
+        ];
+        let counted = [
+            "counted 3 lines with no digit as 0",
+            "counted 2 lines with no digit as 0",
+        ];
+        for (no_digit, remarks) in [(NoDigit::Skip, skipped), (NoDigit::Zero, counted)] {
+            let config = Config {
+                no_digit,
+                ..Config::default()
+            };
+            let input = Day1::parse_with(document, &config, BadLines::Fail).unwrap();
+            assert_eq!(sum(Day1::part_one(&input)), Ok(12 + 33));
+            assert_eq!(sum(Day1::part_two(&input)), Ok(12 + 33 + 44));
+            let sums =
+                stream::read_with::<Day1>(Cursor::new(document), &config, BadLines::Fail).unwrap();
+            assert_eq!(sum(Day1::part_one_answer(&sums)), Ok(12 + 33));
+            assert_eq!(sum(Day1::part_two_answer(&sums)), Ok(12 + 33 + 44));
+
+            let mut options = Options::default();
+            options.configs.set(config);
+            let run = runner::find(2023, 1).unwrap().run(document, &options);
+            let mut reader = Cursor::new(document);
+            let streamed =
+                stream::solve::<Day1>(&mut reader, &Part::ALL, &options.configs, options.bad_lines);
+            for run in [run.unwrap(), streamed.unwrap()] {
+                assert_eq!(
+                    run.answers,
+                    vec![Ok("45".to_string()), Ok("89".to_string())]
+                );
+                assert_eq!(run.remarks, remarks.map(|r| Some(r.to_string())).to_vec());
+            }
+        }
+
+        let no_digit = Err(Error::Validation("line 2 has no digit".to_string()));
+        let mut reader = Cursor::new(document);
+        let streamed =
+            stream::solve::<Day1>(&mut reader, &Part::ALL, &Default::default(), BadLines::Fail);
+        let run = runner::find(2023, 1)
+            .unwrap()
+            .run(document, &Options::default());
+        for run in [run.unwrap(), streamed.unwrap()] {
+            assert_eq!(run.answers, vec![no_digit.clone(), no_digit.clone()]);
+            assert_eq!(run.remarks, vec![None, None]);
+        }
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        // The trailing blank lines are dropped, the others have no digit.
+        let input = input::normalize("1abc2\n\ntreb7uchet\n\n\n");
+        let input = Day1::parse(&input).unwrap();
+        assert_eq!(
+            sum(Day1::part_one(&input)),
+            Err(Error::Validation("line 2 has no digit".to_string()))
+        );
+        let day = runner::find(2023, 1).unwrap();
+        assert_eq!(
+            day.solve("1abc2\ntreb7uchet\n\n \n", &Part::ALL),
+            Ok(vec![Ok("89".to_string()), Ok("89".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_calibration_value() {
+        assert_eq!(calibration_value(&[1, 2, 3], 0, Mode::Numbers), Ok(13));
+        assert_eq!(calibration_value(&[7], 0, Mode::Numbers), Ok(77));
+        assert_eq!(
+            calibration_value(&numbers("twenty-three and five"), 0, Mode::Numbers),
+            Ok(235)
+        );
+        assert_eq!(
+            calibration_value(&numbers("x1abc23"), 0, Mode::Numbers),
+            Ok(123)
+        );
+        assert_eq!(
+            calibration_value(&numbers("one hundred and five then zero"), 0, Mode::Numbers),
+            Ok(1050)
+        );
+        assert_eq!(
+            calibration_value(&[u64::MAX, 1], 4, Mode::Numbers),
+            Err(Error::Validation(format!(
+                "line 5: calibration value {}1 is too large",
+                u64::MAX
+            )))
+        );
+        assert_eq!(
+            calibration_value(&[], 2, Mode::Numbers),
+            Err(Error::Validation("line 3 has no number".to_string()))
+        );
+        assert_eq!("numbers".parse(), Ok(Mode::Numbers));
+        assert!("words".parse::<Mode>().is_err());
+    }
+
+    #[test]
+    fn test_numbers_mode() {
+        let document = "twenty-three and 5\nx1abc23 seven\n";
+        let config = Config {
+            mode: Mode::Numbers,
+            ..Config::default()
+        };
+        let input = Day1::parse_with(document, &config, BadLines::Fail).unwrap();
+        assert_eq!(sum(Day1::part_one(&input)), Ok(55 + 123));
+        assert_eq!(sum(Day1::part_two(&input)), Ok(235 + 17));
+        let sums =
+            stream::read_with::<Day1>(Cursor::new(document), &config, BadLines::Fail).unwrap();
+        assert_eq!(sum(Day1::part_one_answer(&sums)), Ok(55 + 123));
+        assert_eq!(sum(Day1::part_two_answer(&sums)), Ok(235 + 17));
+
+        let input = Day1::parse_with("1abc2\nabc\n", &config, BadLines::Fail).unwrap();
+        assert_eq!(
+            sum(Day1::part_two(&input)),
+            Err(Error::Validation("line 2 has no number".to_string()))
+        );
+
+        let mut options = Options::default();
+        options.configs.set(config);
+        let run = runner::find(2023, 1).unwrap().run(document, &options);
+        assert_eq!(
+            run.unwrap().answers,
+            vec![Ok("178".to_string()), Ok("252".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_samples() {
+        let input = Day1::parse(include_str!("../../data/2023/dec1_sample.txt")).unwrap();
+        assert_eq!(sum(Day1::part_one(&input)), Ok(142));
+        assert_eq!(sum(Day1::part_two(&input)), Ok(142));
+        let input = Day1::parse(include_str!("../../data/2023/dec1_sample2.txt")).unwrap();
+        assert_eq!(sum(Day1::part_two(&input)), Ok(281));
+    }
+}
@@ -0,0 +1,278 @@
+use nom::{
+    branch::alt,
+    character::complete::space1,
+    combinator::cut,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+    Parser,
+};
+
+use crate::{
+    error::Result,
+    parse::{integer, parse_all, parse_lines, tag, BadLines, PResult},
+    solution::Solution,
+    stream::LineSolution,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub samples: Vec<Sample>,
+}
+
+impl Game {
+    pub fn lower_bound(&self) -> Sample {
+        self.samples.iter().fold(Sample::new(0, 0, 0), |max, s| {
+            Sample::new(
+                max.red.max(s.red),
+                max.green.max(s.green),
+                max.blue.max(s.blue),
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl Sample {
+    pub fn new(red: u32, green: u32, blue: u32) -> Self {
+        Self { red, green, blue }
+    }
+
+    pub fn is_possible(&self, config: &Sample) -> bool {
+        self.red <= config.red && self.green <= config.green && self.blue <= config.blue
+    }
+
+    pub fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+fn from_counts(counts: Vec<(u32, &str)>) -> Sample {
+    let red = get_count("red", &counts);
+    let green = get_count("green", &counts);
+    let blue = get_count("blue", &counts);
+    Sample::new(red, green, blue)
+}
+
+fn get_count(color: &str, counts: &[(u32, &str)]) -> u32 {
+    counts
+        .iter()
+        .find(|i| i.1 == color)
+        .map(|i| i.0)
+        .unwrap_or_default()
+}
+
+fn color_count(input: &str) -> PResult<'_, (u32, &str)> {
+    tuple((
+        integer,
+        space1,
+        alt((tag("red"), tag("green"), tag("blue"))),
+    ))
+    .map(|t| (t.0, t.2))
+    .parse(input)
+}
+
+fn sample(input: &str) -> PResult<'_, Sample> {
+    separated_list1(tag(", "), cut(color_count))
+        .map(from_counts)
+        .parse(input)
+}
+
+fn sample_list(input: &str) -> PResult<'_, Vec<Sample>> {
+    separated_list1(tag("; "), cut(sample)).parse(input)
+}
+
+fn game_id(input: &str) -> PResult<'_, u32> {
+    preceded(tag("Game "), integer).parse(input)
+}
+
+fn game(input: &str) -> PResult<'_, Game> {
+    separated_pair(game_id, cut(tag(": ")), cut(sample_list))
+        .map(|(id, s)| Game { id, samples: s })
+        .parse(input)
+}
+
+/// Parse a line of the input, e.g. `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+pub fn parse_game(line: &str) -> Result<Game> {
+    parse_all(game, line)
+}
+
+pub struct Day2;
+
+/// The content of the bag of part 1.
+const CONFIG: Sample = Sample {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+    type Answer = u64;
+    type Config = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &(), BadLines::Fail)
+    }
+
+    fn parse_with(input: &str, _config: &(), bad_lines: BadLines) -> Result<Self::Input> {
+        parse_lines(input, game, bad_lines)
+    }
+
+    fn part_one(games: &Self::Input) -> Result<u64> {
+        Ok(games
+            .iter()
+            .filter(|g| g.samples.iter().all(|s| s.is_possible(&CONFIG)))
+            .map(|g| u64::from(g.id))
+            .sum())
+    }
+
+    fn part_two(games: &Self::Input) -> Result<u64> {
+        Ok(games
+            .iter()
+            .map(|g| g.lower_bound())
+            .map(|s| u64::from(s.power()))
+            .sum())
+    }
+}
+
+impl LineSolution for Day2 {
+    /// The sum of the ids of the possible games and the sum of the powers of the games, which
+    /// may not fit in a `u32` on large streams.
+    type State = (u64, u64);
+
+    fn update((ids, powers): &mut (u64, u64), _ln: usize, line: &str) -> Result<()> {
+        let game = parse_game(line)?;
+        if game.samples.iter().all(|s| s.is_possible(&CONFIG)) {
+            *ids += u64::from(game.id);
+        }
+        *powers += u64::from(game.lower_bound().power());
+        Ok(())
+    }
+
+    fn part_one_answer((ids, _): &(u64, u64)) -> Result<u64> {
+        Ok(*ids)
+    }
+
+    fn part_two_answer((_, powers): &(u64, u64)) -> Result<u64> {
+        Ok(*powers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nom::Finish;
+
+    use crate::{
+        error::Error,
+        solution::Solution,
+        stream::LineSolution,
+        y2023::day2::{
+            color_count, game, game_id, parse_game, sample, sample_list, Day2, Game, Sample,
+        },
+    };
+
+    #[test]
+    fn test_color_count() {
+        assert_eq!(color_count("4 red"), Ok(("", (4, "red"))));
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(
+            sample("4 red, 3 green, 1 blue"),
+            Ok((
+                "",
+                Sample {
+                    red: 4,
+                    green: 3,
+                    blue: 1
+                }
+            ))
+        );
+        assert_eq!(
+            sample("4 blue, 3 red"),
+            Ok((
+                "",
+                Sample {
+                    red: 3,
+                    green: 0,
+                    blue: 4
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_sample_list() {
+        let samples = "11 blue, 13 red, 3 green; 13 red, 1 green, 6 blue";
+        assert_eq!(
+            sample_list(samples),
+            Ok(("", vec![Sample::new(13, 3, 11), Sample::new(13, 1, 6)]))
+        );
+    }
+
+    #[test]
+    fn test_game_id() {
+        assert_eq!(game_id("Game 12"), Ok(("", 12)));
+    }
+
+    #[test]
+    fn test_game() {
+        let game_spec = "Game 9: 11 blue, 13 red, 3 green; 13 red, 1 green, 6 blue";
+        assert_eq!(
+            game(game_spec),
+            Ok((
+                "",
+                Game {
+                    id: 9,
+                    samples: vec![Sample::new(13, 3, 11), Sample::new(13, 1, 6)]
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_lower_bound() {
+        let game = game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")
+            .finish()
+            .unwrap()
+            .1;
+        assert_eq!(game.lower_bound(), Sample::new(4, 2, 6));
+    }
+
+    #[test]
+    fn test_parse_game() {
+        assert_eq!(parse_game("Game 2: 1 red").map(|g| g.id), Ok(2));
+        assert!(parse_game("Game 2: 1 red;").is_err());
+        assert!(parse_game("Game 2: 1 purple").is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Game 1: 3 blue\nGame 2: 3 blue, 4 rod\n";
+        let Err(Error::Parse(e)) = Day2::parse(input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (2, 19));
+        assert_eq!(e.expected, vec!["`red`", "`green`", "`blue`"]);
+        assert_eq!(e.text, "Game 2: 3 blue, 4 rod");
+    }
+
+    #[test]
+    fn test_large_sums() {
+        let mut sums = (u64::from(u32::MAX), u64::from(u32::MAX));
+        Day2::update(&mut sums, 1, "Game 5: 2 red, 3 blue").unwrap();
+        assert_eq!(Day2::part_one_answer(&sums), Ok(u64::from(u32::MAX) + 5));
+        assert_eq!(Day2::part_two_answer(&sums), Ok(u64::from(u32::MAX)));
+    }
+}
@@ -0,0 +1,261 @@
+//! Part 1: for each line, create the following:
+//! - a list of symbol position
+//! - and a list of number + line and (start, end) positions.
+//!
+//! Then for each number query the symbol in the number bounding box. If not empty sum it.
+//!
+//! Part 2: Build the same symbol and numbers. But this time query the number in the surround gear
+//! symbol, if exactly 2, multiply them and sum the result.
+
+use crate::{
+    error::{Error, Result},
+    solution::Solution,
+};
+
+/// A sparse symbol map: for each symbol, it stores the line and column indices (0 to N) in the
+/// input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolMap {
+    size: (usize, usize),
+    symbols: Vec<((usize, usize), char)>,
+}
+
+impl SymbolMap {
+    pub fn new(lines: &[&str]) -> Self {
+        let size = (lines.len(), lines.iter().map(|s| s.len()).max().unwrap());
+
+        let symbols = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(r, l)| {
+                l.chars()
+                    .enumerate()
+                    .filter_map(|(c, a)| {
+                        if a != '.' && !a.is_ascii_digit() {
+                            Some(((r, c), a))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        SymbolMap { size, symbols }
+    }
+
+    pub fn query(
+        &self,
+        rows: (usize, usize),
+        columns: (usize, usize),
+    ) -> Vec<((usize, usize), char)> {
+        self.symbols
+            .iter()
+            .filter(|((r, c), _)| {
+                *r >= rows.0 && *r <= rows.1 && *c >= columns.0 && *c <= columns.1
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn gears<'a>(&'a self) -> impl Iterator<Item = ((usize, usize), char)> + 'a {
+        self.symbols.iter().filter(|((_, _), a)| *a == '*').cloned()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberMap {
+    pub size: (usize, usize),
+    pub numbers: Vec<(usize, (usize, usize), u64)>,
+}
+
+impl NumberMap {
+    /// Fails if a number does not fit in a `u64`.
+    pub fn new(lines: &[&str]) -> Result<Self> {
+        let size = (lines.len(), lines.iter().map(|s| s.len()).max().unwrap());
+        let mut numbers = vec![];
+        for (r, l) in lines.iter().enumerate() {
+            // Columns count characters, as in the symbol map.
+            let chars: Vec<char> = l.chars().collect();
+            let number = |start: usize, end: usize| {
+                let digits: String = chars[start..=end].iter().collect();
+                digits.parse::<u64>().map_err(|_| {
+                    Error::Validation(format!(
+                        "line {}, column {}: {digits} is too large",
+                        r + 1,
+                        start + 1
+                    ))
+                })
+            };
+            let mut start = None;
+            for (c, &a) in chars.iter().enumerate() {
+                if start.is_none() {
+                    if a.is_ascii_digit() {
+                        start = Some(c);
+                    }
+                } else if !a.is_ascii_digit() {
+                    let start_ix = start.unwrap();
+                    start = None;
+                    numbers.push((r, (start_ix, c - 1), number(start_ix, c - 1)?));
+                }
+            }
+            if let Some(s) = start {
+                numbers.push((r, (s, chars.len() - 1), number(s, chars.len() - 1)?));
+            }
+        }
+        Ok(Self { size, numbers })
+    }
+
+    pub fn query(&self, pos: (usize, usize)) -> Vec<u64> {
+        self.numbers
+            .iter()
+            .filter_map(|(l, (s, e), n)| {
+                if *l < pos.0.saturating_sub(1)
+                    || *l > pos.0 + 1
+                    || *e < pos.1.saturating_sub(1)
+                    || *s > pos.1 + 1
+                {
+                    None
+                } else {
+                    Some(*n)
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    type Input = (SymbolMap, NumberMap);
+    type Answer = u64;
+    type Config = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<_> = input.lines().collect();
+        if lines.is_empty() {
+            return Err(Error::Validation("empty schematic".to_string()));
+        }
+        Ok((SymbolMap::new(&lines), NumberMap::new(&lines)?))
+    }
+
+    fn part_one((symbols, numbers): &Self::Input) -> Result<u64> {
+        let sum = numbers
+            .numbers
+            .iter()
+            .filter_map(|(r, (s, e), n)| {
+                let (line_count, col_count) = numbers.size;
+                let min_row = if *r == 0 { 0 } else { *r - 1 };
+                let max_row = if *r == line_count - 1 { *r } else { *r + 1 };
+                let min_col = if *s == 0 { 0 } else { *s - 1 };
+                let max_col = if *e == col_count - 1 { *e } else { *e + 1 };
+                let symbols = symbols.query((min_row, max_row), (min_col, max_col));
+                if !symbols.is_empty() {
+                    Some(n)
+                } else {
+                    None
+                }
+            })
+            .sum();
+        Ok(sum)
+    }
+
+    fn part_two((symbols, numbers): &Self::Input) -> Result<u64> {
+        let sum = symbols
+            .gears()
+            .filter_map(|((r, c), _)| {
+                let numbers = numbers.query((r, c));
+                if numbers.len() == 2 {
+                    Some(numbers.iter().product::<u64>())
+                } else {
+                    None
+                }
+            })
+            .sum();
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        solution::Solution,
+        y2023::day3::{Day3, NumberMap, SymbolMap},
+    };
+
+    #[test]
+    fn test_symbolmap() {
+        let input = include_str!("../../data/2023/dec3_sample.txt");
+        let lines = input.lines().collect::<Vec<_>>();
+        assert_eq!(
+            SymbolMap::new(&lines),
+            SymbolMap {
+                size: (10, 10),
+                symbols: vec![
+                    ((1, 3), '*'),
+                    ((3, 6), '#'),
+                    ((4, 3), '*'),
+                    ((5, 5), '+'),
+                    ((8, 3), '$'),
+                    ((8, 5), '*')
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_numbermap() {
+        let input = include_str!("../../data/2023/dec3_sample.txt");
+        let lines = input.lines().collect::<Vec<_>>();
+        assert_eq!(
+            NumberMap::new(&lines).unwrap().numbers,
+            vec![
+                (0, (0, 2), 467),
+                (0, (5, 7), 114),
+                (2, (2, 3), 35),
+                (2, (6, 8), 633),
+                (4, (0, 2), 617),
+                (5, (7, 8), 58),
+                (6, (2, 4), 592),
+                (7, (6, 8), 755),
+                (9, (1, 3), 664),
+                (9, (5, 7), 598),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gear_on_edge() {
+        let lines = ["*12.", "...."];
+        assert_eq!(NumberMap::new(&lines).unwrap().query((0, 0)), vec![12]);
+        let input = Day3::parse("*12.\n3...\n").unwrap();
+        assert_eq!(Day3::part_one(&input), Ok(15));
+        assert_eq!(Day3::part_two(&input), Ok(36));
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        let lines = ["é12", "..£"];
+        assert_eq!(
+            NumberMap::new(&lines).unwrap().numbers,
+            vec![(0, (1, 2), 12)]
+        );
+        assert_eq!(
+            SymbolMap::new(&lines).symbols,
+            vec![((0, 0), 'é'), ((1, 2), '£')]
+        );
+    }
+
+    #[test]
+    fn test_large_number() {
+        let digits = "1".repeat(21);
+        assert_eq!(
+            Day3::parse(&format!("..*\n.{digits}\n")),
+            Err(Error::Validation(format!(
+                "line 2, column 2: {digits} is too large"
+            )))
+        );
+    }
+}
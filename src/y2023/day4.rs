@@ -0,0 +1,224 @@
+use nom::{
+    character::complete::space1,
+    sequence::{pair, preceded, tuple},
+    Parser,
+};
+
+use crate::{
+    error::{Error, Result},
+    parse::{integer, number_list, parse_all, parse_lines, tag, BadLines, PResult},
+    solution::Solution,
+    stream::LineSolution,
+};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    id: u16,
+    winnings: Vec<u16>,
+    numbers: Vec<u16>,
+    pub matching: u16,
+}
+
+impl Card {
+    pub fn new(id: u16, winnings: Vec<u16>, numbers: Vec<u16>) -> Self {
+        let matching = numbers.iter().filter(|n| winnings.contains(n)).count() as u16;
+        Self {
+            id,
+            winnings,
+            numbers,
+            matching,
+        }
+    }
+
+    pub fn value(&self) -> u16 {
+        if self.matching == 0 {
+            0
+        } else {
+            2_u16.pow((self.matching - 1) as u32)
+        }
+    }
+}
+
+fn card(input: &str) -> PResult<'_, Card> {
+    tuple((
+        card_id,
+        tag(":"),
+        space1,
+        number_list,
+        tag(" |"),
+        space1,
+        number_list,
+    ))
+    .map(|(id, _, _, winnings, _, _, numbers)| Card::new(id, winnings, numbers))
+    .parse(input)
+}
+
+/// Parse a line of the input, e.g. `Card 1: 41 48 83 | 83 86  6`.
+pub fn parse_card(line: &str) -> Result<Card> {
+    parse_all(card, line)
+}
+
+fn card_id(input: &str) -> PResult<'_, u16> {
+    preceded(pair(tag("Card"), space1), integer).parse(input)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+    type Answer = u64;
+    type Config = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &(), BadLines::Fail)
+    }
+
+    fn parse_with(input: &str, _config: &(), bad_lines: BadLines) -> Result<Self::Input> {
+        parse_lines(input, card, bad_lines)
+    }
+
+    fn part_one(cards: &Self::Input) -> Result<u64> {
+        Ok(cards.iter().map(|c| u64::from(c.value())).sum())
+    }
+
+    fn part_two(cards: &Self::Input) -> Result<u64> {
+        let mut num_cards = vec![1_u64; cards.len()];
+        for i in 0..cards.len() {
+            let matching = cards[i].matching;
+            if i + matching as usize >= cards.len() {
+                return Err(Error::Validation(format!(
+                    "card {} wins copies of cards past the last one",
+                    cards[i].id
+                )));
+            }
+            if matching > 0 {
+                for j in i + 1..=(i + matching as usize) {
+                    num_cards[j] += num_cards[i];
+                }
+            }
+        }
+        Ok(num_cards.iter().sum::<u64>())
+    }
+}
+
+/// The running answers of day 4, whose memory is bounded by the number of matching numbers of
+/// a card. The sums are `u64`s, as large streams overflow a `u32`.
+#[derive(Debug, Clone, Default)]
+pub struct Pile {
+    points: u64,
+    cards: u64,
+    /// The number of cards read so far.
+    count: usize,
+    /// The copies won of the next cards.
+    copies: VecDeque<u64>,
+    /// The cards which win copies past the last card read so far, as (index of the last
+    /// card won, id), each reaching further than the previous one.
+    overflows: VecDeque<(usize, u16)>,
+}
+
+impl LineSolution for Day4 {
+    type State = Pile;
+
+    fn update(pile: &mut Pile, _ln: usize, line: &str) -> Result<()> {
+        let card = parse_card(line)?;
+        pile.points += u64::from(card.value());
+        let n = 1 + pile.copies.pop_front().unwrap_or(0);
+        pile.cards += n;
+        for j in 0..card.matching as usize {
+            match pile.copies.get_mut(j) {
+                Some(c) => *c += n,
+                None => pile.copies.push_back(n),
+            }
+        }
+
+        let reach = pile.count + card.matching as usize;
+        pile.count += 1;
+        if pile.overflows.back().is_none_or(|&(r, _)| reach > r) {
+            pile.overflows.push_back((reach, card.id));
+        }
+        while pile.overflows.front().is_some_and(|&(r, _)| r < pile.count) {
+            pile.overflows.pop_front();
+        }
+        Ok(())
+    }
+
+    fn part_one_answer(pile: &Pile) -> Result<u64> {
+        Ok(pile.points)
+    }
+
+    fn part_two_answer(pile: &Pile) -> Result<u64> {
+        match pile.overflows.front() {
+            Some((_, id)) => Err(Error::Validation(format!(
+                "card {id} wins copies of cards past the last one"
+            ))),
+            None => Ok(pile.cards),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        solution::Solution,
+        stream::LineSolution,
+        y2023::day4::{card, parse_card, Card, Day4, Pile},
+    };
+
+    #[test]
+    fn test_card() {
+        assert_eq!(
+            card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")
+                .unwrap()
+                .1,
+            Card::new(
+                1,
+                vec![41, 48, 83, 86, 17],
+                vec![83, 86, 6, 31, 17, 9, 48, 53]
+            )
+        );
+    }
+
+    #[test]
+    fn test_value() {
+        assert_eq!(
+            card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")
+                .unwrap()
+                .1
+                .value(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_parse_card() {
+        assert_eq!(parse_card("Card 2: 1 | 1").map(|c| c.value()), Ok(1));
+        assert!(parse_card("Card 2: 1 | 1 x").is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Card 1: 1 | 1\nCard 2: 1 2 | 1\nCard 3 1 | 1\n";
+        let Err(Error::Parse(e)) = Day4::parse(input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (3, 7));
+        assert_eq!(e.expected, vec!["`:`"]);
+    }
+
+    #[test]
+    fn test_large_sums() {
+        let mut pile = Pile {
+            points: u64::from(u32::MAX),
+            cards: u64::from(u32::MAX),
+            ..Pile::default()
+        };
+        Day4::update(&mut pile, 1, "Card 1: 1 2 | 1 3").unwrap();
+        Day4::update(&mut pile, 2, "Card 2: 1 2 | 3 4").unwrap();
+        assert_eq!(Day4::part_one_answer(&pile), Ok(u64::from(u32::MAX) + 1));
+        assert_eq!(Day4::part_two_answer(&pile), Ok(u64::from(u32::MAX) + 3));
+    }
+}
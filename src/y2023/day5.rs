@@ -0,0 +1,452 @@
+use log::{debug, trace};
+use nom::{
+    bytes::complete::is_not,
+    character::complete::{char, line_ending, space1},
+    combinator::{consumed, cut, opt},
+    error::context,
+    sequence::{pair, preceded, terminated, tuple},
+    Parser,
+};
+use std::{fmt::Display, iter::Iterator};
+
+use crate::{
+    error::{Error, Result},
+    interval::Interval,
+    parse::{integer, labeled, lines, number_list, parse_all, sections, Expected, PResult},
+    solution::Solution,
+};
+
+/// An integer mapping (Interval, dst) maps integer in Interval to an interval of the same lenght
+/// starting at dst.
+/// Eg ((2, 2), 1) maps 2 and 3 to 1 and 2 respectively.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mapping {
+    source: Interval,
+    dst: u64,
+}
+
+impl Mapping {
+    pub fn source(&self) -> Interval {
+        self.source
+    }
+
+    pub fn destination(&self) -> Interval {
+        Interval::start_len(self.dst, self.source.len())
+    }
+
+    pub fn map(&self, v: u64) -> u64 {
+        assert!(self.source.contains(v));
+        self.dst + (v - self.source.min)
+    }
+}
+
+impl Display for Mapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.source, self.destination())
+    }
+}
+
+impl From<(u64, u64, u64)> for Mapping {
+    /// Create a Mapping from (dst, src, len).
+    fn from(value: (u64, u64, u64)) -> Self {
+        Self {
+            source: Interval::start_len(value.1, value.2),
+            dst: value.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalMap {
+    mappings: Vec<Mapping>,
+}
+
+impl IntervalMap {
+    /// Build the map of the given mappings, or return the index of the last mapping whose source
+    /// overlaps the source of another one.
+    pub fn new(mappings: Vec<Mapping>) -> std::result::Result<Self, usize> {
+        let mut mappings: Vec<(usize, Mapping)> = mappings.into_iter().enumerate().collect();
+        mappings.sort_by_key(|(_, m)| m.source.min);
+        if let Some(w) = mappings
+            .windows(2)
+            .find(|w| !w[0].1.source.lt(w[1].1.source.min))
+        {
+            return Err(w[0].0.max(w[1].0));
+        }
+        Ok(Self {
+            mappings: mappings.into_iter().map(|(_, m)| m).collect(),
+        })
+    }
+
+    pub fn map(&self, n: u64) -> u64 {
+        let rng_mapping = self.mappings.iter().find(|&m| m.source.contains(n));
+        if let Some(m) = rng_mapping {
+            m.map(n)
+        } else {
+            n
+        }
+    }
+
+    pub fn map_interval(&self, interval: Interval) -> Vec<Interval> {
+        let mut unmapped = Some(interval);
+        let mut mapped = vec![];
+        for m in self.mappings.iter() {
+            if m.source.lt(unmapped.unwrap().min) {
+                continue;
+            }
+            if m.source.gt(unmapped.unwrap().max) {
+                mapped.push(unmapped.unwrap());
+                unmapped = None;
+                break;
+            }
+            let (l, r) = unmapped.unwrap().split_before(m.source.min);
+            if let Some(i) = l {
+                mapped.push(i);
+            }
+            unmapped = r;
+            if unmapped.is_none() {
+                break;
+            }
+
+            let (l, r) = unmapped.unwrap().split_after(m.source.max);
+            if let Some(i) = l {
+                mapped.push(Interval::start_len(m.map(i.min), i.len()));
+            }
+            unmapped = r;
+            if unmapped.is_none() {
+                break;
+            }
+        }
+
+        // if all mapping are less than the interval push as is.
+        if let Some(i) = unmapped {
+            mapped.push(i);
+        }
+
+        trace!("{interval} -> {mapped:?}");
+        mapped
+    }
+}
+
+impl Display for IntervalMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for m in &self.mappings {
+            writeln!(f, "{m}")?;
+        }
+        Ok(())
+    }
+}
+
+fn seeds(input: &str) -> PResult<'_, (Vec<u64>, Vec<Interval>)> {
+    terminated(labeled("seeds", seed_list), line_ending).parse(input)
+}
+
+fn header(input: &str) -> PResult<'_, &str> {
+    context(
+        "a map header",
+        terminated(is_not(":\r\n"), pair(char(':'), line_ending)),
+    )
+    .parse(input)
+}
+
+/// The seeds, which are also ranges of seeds when read two by two.
+fn seed_list(input: &str) -> PResult<'_, (Vec<u64>, Vec<Interval>)> {
+    let (rest, seeds) = number_list(input)?;
+    if seeds.len() % 2 != 0 {
+        let e = Expected::new(input, "an even number of seeds");
+        return Err(nom::Err::Error(e));
+    }
+    let ranges = seeds
+        .chunks_exact(2)
+        .map(|s| Interval::checked_start_len(s[0], s[1]))
+        .collect::<Option<_>>()
+        .ok_or_else(|| {
+            let e = Expected::new(input, "seed ranges of positive length ending below 2^64");
+            nom::Err::Error(e)
+        })?;
+    Ok((rest, (seeds, ranges)))
+}
+
+/// The length of a mapping, which cannot be empty.
+fn length(input: &str) -> PResult<'_, u64> {
+    let (rest, len) = integer(input)?;
+    if len == 0 {
+        return Err(nom::Err::Error(Expected::new(input, "a positive length")));
+    }
+    Ok((rest, len))
+}
+
+fn mapping(input: &str) -> PResult<'_, Mapping> {
+    let (rest, (dst, _, src, _, len)) =
+        tuple((integer, space1, integer, space1, length)).parse(input)?;
+    match (
+        Interval::checked_start_len(src, len),
+        Interval::checked_start_len(dst, len),
+    ) {
+        (Some(source), Some(_)) => Ok((rest, Mapping { source, dst })),
+        _ => Err(nom::Err::Failure(Expected::new(
+            input,
+            "a mapping of ranges ending below 2^64",
+        ))),
+    }
+}
+
+/// A header and at least one mapping, none overlapping another one.
+fn interval_map(input: &str) -> PResult<'_, IntervalMap> {
+    let (rest, located) = preceded(header, cut(lines(consumed(mapping)))).parse(input)?;
+    let (lines, mappings): (Vec<&str>, Vec<Mapping>) = located.into_iter().unzip();
+    match IntervalMap::new(mappings) {
+        Ok(map) => Ok((rest, map)),
+        Err(ix) => Err(nom::Err::Failure(Expected::new(
+            lines[ix],
+            "a mapping not overlapping the previous ones",
+        ))),
+    }
+}
+
+/// The seeds, read both as a list of values and as a list of ranges, and the maps of the almanac.
+pub type Almanac = ((Vec<u64>, Vec<Interval>), Vec<IntervalMap>);
+
+fn seeds_and_maps(input: &str) -> PResult<'_, Almanac> {
+    tuple((seeds, line_ending, sections(interval_map)))
+        .map(|(s, _, m)| (s, m))
+        .parse(input)
+}
+
+/// Parse the whole almanac.
+pub fn parse_almanac(input: &str) -> Result<Almanac> {
+    parse_all(terminated(seeds_and_maps, opt(line_ending)), input)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+    type Input = Almanac;
+    type Answer = u64;
+    type Config = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_almanac(input)
+    }
+
+    fn part_one(((seeds, _), maps): &Self::Input) -> Result<u64> {
+        seeds
+            .iter()
+            .map(|s| {
+                let mut v = *s;
+                for m in maps {
+                    v = m.map(v);
+                }
+                v
+            })
+            .min()
+            .ok_or_else(|| Error::NoSolution("no seeds".to_string()))
+    }
+
+    fn part_two(((_, seed_ranges), maps): &Self::Input) -> Result<u64> {
+        let mut unmapped = Vec::new();
+        unmapped.extend_from_slice(seed_ranges);
+        unmapped.sort_by(|a, b| a.min.partial_cmp(&b.min).unwrap());
+        for (ix, m) in maps.iter().enumerate() {
+            debug!("map {ix}:\n{m}");
+            let mut next_unmapped = unmapped
+                .iter()
+                .flat_map(|&r| m.map_interval(r).into_iter())
+                .collect::<Vec<_>>();
+            next_unmapped.sort_by(|a, b| a.min.partial_cmp(&b.min).unwrap());
+            debug!("map {ix}: output = {next_unmapped:?}");
+            unmapped = next_unmapped;
+        }
+
+        let min_interval = unmapped
+            .first()
+            .ok_or_else(|| Error::NoSolution("no seed ranges".to_string()))?;
+        debug!("lowest location range {min_interval}");
+        Ok(min_interval.min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interval::Interval,
+        solution::Solution,
+        y2023::day5::{interval_map, mapping, parse_almanac, seeds, Day5, IntervalMap, Mapping},
+    };
+
+    #[test]
+    fn test_seeds() {
+        assert_eq!(
+            seeds("seeds: 1 2 3 4\n"),
+            Ok((
+                "",
+                (
+                    vec![1, 2, 3, 4],
+                    vec![Interval::start_len(1, 2), Interval::start_len(3, 4)]
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_mapping() {
+        assert_eq!(
+            mapping("50 98 2"),
+            Ok((
+                "",
+                Mapping {
+                    source: Interval::start_len(98, 2),
+                    dst: 50
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_interval_map() {
+        assert_eq!(
+            interval_map("seed-to-soil map:\n1 2 3\n4 5 6"),
+            Ok((
+                "",
+                IntervalMap::new(vec![
+                    Mapping {
+                        source: Interval::start_len(2, 3),
+                        dst: 1
+                    },
+                    Mapping {
+                        source: Interval::start_len(5, 6),
+                        dst: 4
+                    },
+                ])
+                .unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_mapping_map() {
+        let mapping = Mapping::from((50, 98, 2));
+        assert_eq!(mapping.map(98), 50);
+        assert_eq!(mapping.map(99), 51);
+    }
+
+    #[test]
+    fn test_intervalmap_map() {
+        let rng = IntervalMap::new(vec![
+            Mapping::from((50, 98, 2)),
+            Mapping::from((52, 50, 48)),
+        ])
+        .unwrap();
+        assert_eq!(rng.map(10), 10);
+        assert_eq!(rng.map(50), 52);
+        assert_eq!(rng.map(98), 50);
+
+        let overlapping = IntervalMap::new(vec![
+            Mapping::from((1, 2, 3)),
+            Mapping::from((5, 20, 1)),
+            Mapping::from((10, 3, 3)),
+        ]);
+        assert_eq!(overlapping, Err(2));
+    }
+
+    #[test]
+    fn test_intervalmap_map_interval() {
+        let map = IntervalMap::new(vec![
+            Mapping::from((50, 98, 2)),  // [98, 99] -> [50, 51]
+            Mapping::from((52, 50, 48)), // [50..97] -> [52..100]
+        ])
+        .unwrap();
+        let a = Interval::min_max(10, 15);
+        assert_eq!(map.map_interval(a), vec![a]);
+
+        let b = Interval::min_max(60, 97);
+        assert_eq!(map.map_interval(b), vec![Interval::min_max(62, 99)]);
+
+        let c = Interval::min_max(95, 99);
+        assert_eq!(
+            map.map_interval(c),
+            vec![Interval::min_max(97, 99), Interval::min_max(50, 51),]
+        )
+    }
+
+    #[test]
+    fn test_parse_almanac() {
+        let ((seeds, ranges), maps) =
+            parse_almanac(include_str!("../../data/2023/dec5_sample.txt")).unwrap();
+        assert_eq!(seeds, vec![79, 14, 55, 13]);
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(maps.len(), 7);
+        assert!(parse_almanac("seeds: 1 2\n\nseed-to-soil map:\n1 2\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "seeds: 1 2\n\na map:\n1 2 3\n\nb map:\n1 2 3\n50 98 x\n";
+        let Err(Error::Parse(e)) = parse_almanac(input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (8, 7));
+        assert_eq!(e.expected, vec!["a number"]);
+
+        let Err(Error::Parse(e)) = parse_almanac("seeds: 1 2\n\na map:\n1 2 3\n\nb map\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (6, 6));
+        assert_eq!(e.expected, vec!["`:`"]);
+
+        let Err(Error::Parse(e)) = parse_almanac("seeds: 1 2\n\na map:\n1 2 3\n4 5 0\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (5, 5));
+        assert_eq!(e.expected, vec!["a positive length"]);
+
+        let Err(Error::Parse(e)) = parse_almanac("seeds: 1 0\n\na map:\n1 2 3\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (1, 8));
+        assert_eq!(
+            e.expected,
+            vec!["seed ranges of positive length ending below 2^64"]
+        );
+
+        let Err(Error::Parse(e)) = parse_almanac("seeds: 1 2 3\n\na map:\n1 2 3\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (1, 8));
+        assert_eq!(e.expected, vec!["an even number of seeds"]);
+
+        let Err(Error::Parse(e)) = parse_almanac(&format!("seeds: {} 2\n", u64::MAX)) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (1, 8));
+
+        let input = format!("seeds: 1 2\n\na map:\n1 2 3\n{} 5 2\n", u64::MAX);
+        let Err(Error::Parse(e)) = parse_almanac(&input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (5, 1));
+        assert_eq!(e.expected, vec!["a mapping of ranges ending below 2^64"]);
+
+        let input = "seeds: 1 2\n\na map:\n1 2 3\n10 3 3\n";
+        let Err(Error::Parse(e)) = parse_almanac(input) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (5, 1));
+        assert_eq!(
+            e.expected,
+            vec!["a mapping not overlapping the previous ones"]
+        );
+    }
+
+    #[test]
+    fn test_no_seeds() {
+        let almanac = ((vec![], vec![]), vec![]);
+        assert_eq!(
+            Day5::part_one(&almanac),
+            Err(Error::NoSolution("no seeds".to_string()))
+        );
+    }
+}
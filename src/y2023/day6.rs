@@ -0,0 +1,236 @@
+use log::debug;
+use nom::{
+    branch::alt,
+    character::complete::line_ending,
+    combinator::eof,
+    sequence::{pair, terminated},
+    Parser,
+};
+
+use crate::{
+    error::{Error, Result},
+    parse::{kerned, labeled, number_list, parse_all, PResult},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Race {
+    duration_ms: u64,
+    record_mm: u64,
+}
+
+impl Race {
+    /// The distance travelled holding the button `t` ms, which may not fit in a `u64` when the
+    /// digits of the race are joined.
+    fn distance(&self, t: u64) -> u128 {
+        u128::from(self.duration_ms - t) * u128::from(t)
+    }
+
+    /// The shortest and the longest times holding the button which beat the record.
+    pub fn solve(&self) -> Result<(u64, u64)> {
+        let record = u128::from(self.record_mm);
+        if self.distance(self.duration_ms / 2) <= record {
+            return Err(Error::NoSolution(format!(
+                "the record of {} mm cannot be beaten in {} ms",
+                self.record_mm, self.duration_ms
+            )));
+        }
+        let mut bound = (0, self.duration_ms / 2);
+        loop {
+            let (low, high) = bound;
+            if low == high {
+                break;
+            }
+            let mid = (high + low) / 2;
+            if self.distance(mid) <= record {
+                bound = (mid + 1, high);
+            } else {
+                bound = (low, mid);
+            }
+        }
+        Ok((bound.0, self.duration_ms - bound.0))
+    }
+}
+
+fn time_list(input: &str) -> PResult<'_, Vec<u64>> {
+    terminated(labeled("Time", number_list), line_ending).parse(input)
+}
+
+fn distance_list(input: &str) -> PResult<'_, Vec<u64>> {
+    terminated(labeled("Distance", number_list), alt((line_ending, eof))).parse(input)
+}
+
+fn race_list(input: &str) -> PResult<'_, (Vec<u64>, Vec<u64>)> {
+    pair(time_list, distance_list).parse(input)
+}
+
+fn duration(input: &str) -> PResult<'_, u64> {
+    terminated(labeled("Time", kerned), line_ending).parse(input)
+}
+
+fn record(input: &str) -> PResult<'_, u64> {
+    terminated(labeled("Distance", kerned), alt((line_ending, eof))).parse(input)
+}
+
+fn race(input: &str) -> PResult<'_, Race> {
+    pair(duration, record)
+        .map(|(d, r)| Race {
+            duration_ms: d,
+            record_mm: r,
+        })
+        .parse(input)
+}
+
+/// Parse the races of the input, one per column.
+pub fn parse_races(input: &str) -> Result<Vec<Race>> {
+    let (times, distances) = parse_all(race_list, input)?;
+    if times.len() != distances.len() {
+        return Err(Error::Validation(format!(
+            "{} times but {} distances",
+            times.len(),
+            distances.len()
+        )));
+    }
+    Ok(times
+        .iter()
+        .zip(distances.iter())
+        .map(|(&duration_ms, &record_mm)| Race {
+            duration_ms,
+            record_mm,
+        })
+        .collect())
+}
+
+/// Parse the input as a single race, ignoring the spaces between the digits.
+pub fn parse_race(input: &str) -> Result<Race> {
+    parse_all(race, input)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+    /// The races of part 1 and the single race of part 2, ignoring the kerning.
+    type Input = (Vec<Race>, Race);
+    type Answer = u64;
+    type Config = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((parse_races(input)?, parse_race(input)?))
+    }
+
+    fn part_one((races, _): &Self::Input) -> Result<u64> {
+        races
+            .iter()
+            .map(|r| {
+                let (l, h) = r.solve()?;
+                Ok(h - l + 1)
+            })
+            .product()
+    }
+
+    fn part_two((_, race): &Self::Input) -> Result<u64> {
+        let (low, high) = race.solve()?;
+        debug!("{race:?} is won holding the button from {low} to {high} ms");
+        Ok(high - low + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        solution::Solution,
+        y2023::day6::{parse_race, parse_races, Day6, Race},
+    };
+
+    #[test]
+    fn test_distance() {
+        let race = Race {
+            duration_ms: 7,
+            record_mm: 9,
+        };
+        assert_eq!(race.distance(3), 12);
+        assert_eq!(race.distance(4), 12);
+        let long = Race {
+            duration_ms: 99999999999,
+            record_mm: 1,
+        };
+        assert_eq!(long.distance(49999999999), 2499999999950000000000);
+    }
+
+    #[test]
+    fn test_solve() {
+        let race = Race {
+            duration_ms: 7,
+            record_mm: 9,
+        };
+        println!("{}", race.distance(2));
+        println!("{}", race.distance(5));
+        assert_eq!(race.solve(), Ok((2, 5)));
+
+        let race2 = Race {
+            duration_ms: 30,
+            record_mm: 200,
+        };
+        println!("{}", race2.distance(10));
+        println!("{}", race2.distance(20));
+        assert_eq!(race2.solve(), Ok((11, 19)));
+    }
+
+    #[test]
+    fn test_long_race() {
+        let input = Day6::parse("Time: 99999999999\nDistance: 1\n").unwrap();
+        assert_eq!(Day6::part_two(&input), Ok(99999999999 - 1));
+    }
+
+    #[test]
+    fn test_unbeatable() {
+        let no_solution =
+            Error::NoSolution("the record of 100 mm cannot be beaten in 5 ms".to_string());
+        let race = Race {
+            duration_ms: 5,
+            record_mm: 100,
+        };
+        assert_eq!(race.solve(), Err(no_solution.clone()));
+        // The record of a race held for exactly the best time is not beaten either.
+        let race = Race {
+            duration_ms: 6,
+            record_mm: 9,
+        };
+        assert!(race.solve().is_err());
+
+        let input = Day6::parse("Time: 5\nDistance: 100\n").unwrap();
+        assert_eq!(Day6::part_one(&input), Err(no_solution.clone()));
+        assert_eq!(Day6::part_two(&input), Err(no_solution));
+    }
+
+    #[test]
+    fn test_parse_races() {
+        assert_eq!(
+            parse_races("Time: 7 15\nDistance: 9 40\n"),
+            Ok(vec![
+                Race {
+                    duration_ms: 7,
+                    record_mm: 9
+                },
+                Race {
+                    duration_ms: 15,
+                    record_mm: 40
+                }
+            ])
+        );
+        assert_eq!(
+            parse_races("Time: 7 15\nDistance: 9"),
+            Err(Error::Validation("2 times but 1 distances".to_string()))
+        );
+        assert_eq!(
+            parse_race("Time: 7 15\nDistance: 9 40"),
+            Ok(Race {
+                duration_ms: 715,
+                record_mm: 940
+            })
+        );
+    }
+}
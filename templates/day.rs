@@ -0,0 +1,47 @@
+use nom::{character::complete::not_line_ending, Parser};
+
+use crate::{
+    error::{Error, Result},
+    parse::{parse_lines, BadLines, PResult},
+    solution::Solution,
+};
+
+pub struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    const YEAR: u16 = __YEAR__;
+    const DAY: u8 = __DAY__;
+    type Input = Vec<String>;
+    type Answer = u64;
+    type Config = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &(), BadLines::Fail)
+    }
+
+    fn parse_with(input: &str, _config: &(), bad_lines: BadLines) -> Result<Self::Input> {
+        parse_lines(input, line, bad_lines)
+    }
+
+    fn part_one(_input: &Self::Input) -> Result<u64> {
+        Err(Error::NoSolution("not solved yet".to_string()))
+    }
+
+    fn part_two(_input: &Self::Input) -> Result<u64> {
+        Err(Error::NoSolution("not solved yet".to_string()))
+    }
+}
+
+fn line(input: &str) -> PResult<'_, String> {
+    not_line_ending.map(String::from).parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y__YEAR__::day__DAY__::line;
+
+    #[test]
+    fn test_line() {
+        assert_eq!(line("abc"), Ok(("", "abc".to_string())));
+    }
+}